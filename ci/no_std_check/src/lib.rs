@@ -0,0 +1,16 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::vec;
+use vector_math::error::MathError;
+use vector_math::matrix::Matrix;
+use vector_math::vector::Vector;
+
+pub fn exercise() -> Result<f64, MathError> {
+    let a = Matrix::new(vec![vec![4.0, 1.0], vec![1.0, 3.0]])?;
+    let x = a.solve(&[1.0, 2.0])?;
+    let (_, s, _) = a.svd(1e-12, 1000)?;
+    let v = Vector(x);
+    Ok(v.norm()? + s[0] + a.determinant()?)
+}
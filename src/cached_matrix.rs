@@ -0,0 +1,143 @@
+use crate::error::MathError;
+use crate::matrix::Matrix;
+use core::cell::RefCell;
+use core::ops::{Index, IndexMut};
+
+/// Matrix wrapper that memoizes the determinant and inverse until the next edit.
+///
+/// Every mutation goes through the wrapper (`set_col`, `swap_rows`, `IndexMut`),
+/// so each one clears the cache; the inner matrix is only exposed immutably.
+#[derive(Debug, Clone)]
+pub struct CachedMatrix {
+    inner: Matrix,
+    determinant: RefCell<Option<f64>>,
+    inverse: RefCell<Option<Matrix>>,
+}
+
+impl CachedMatrix {
+    pub fn new(inner: Matrix) -> Self {
+        Self {
+            inner,
+            determinant: RefCell::new(None),
+            inverse: RefCell::new(None),
+        }
+    }
+
+    pub fn matrix(&self) -> &Matrix {
+        &self.inner
+    }
+
+    pub fn into_inner(self) -> Matrix {
+        self.inner
+    }
+
+    /// True when neither the determinant nor the inverse is cached
+    pub fn is_dirty(&self) -> bool {
+        self.determinant.borrow().is_none() && self.inverse.borrow().is_none()
+    }
+
+    pub fn determinant(&self) -> Result<f64, MathError> {
+        if let Some(det) = *self.determinant.borrow() {
+            return Ok(det);
+        }
+        let det = self.inner.determinant()?;
+        *self.determinant.borrow_mut() = Some(det);
+        Ok(det)
+    }
+
+    pub fn inverse(&self) -> Result<Matrix, MathError> {
+        if let Some(inv) = self.inverse.borrow().as_ref() {
+            return Ok(inv.clone());
+        }
+        let inv = self.inner.inverse()?;
+        *self.inverse.borrow_mut() = Some(inv.clone());
+        Ok(inv)
+    }
+
+    pub fn set_col(&mut self, idx: usize, col: &[f64]) -> Result<(), MathError> {
+        self.invalidate();
+        self.inner.set_col(idx, col)
+    }
+
+    pub fn swap_rows(&mut self, i: usize, j: usize) -> Result<(), MathError> {
+        self.invalidate();
+        self.inner.swap_rows(i, j)
+    }
+
+    fn invalidate(&mut self) {
+        *self.determinant.get_mut() = None;
+        *self.inverse.get_mut() = None;
+    }
+}
+
+impl From<Matrix> for CachedMatrix {
+    fn from(inner: Matrix) -> Self {
+        Self::new(inner)
+    }
+}
+
+impl Index<(usize, usize)> for CachedMatrix {
+    type Output = f64;
+
+    fn index(&self, idx: (usize, usize)) -> &f64 {
+        &self.inner[idx]
+    }
+}
+
+impl IndexMut<(usize, usize)> for CachedMatrix {
+    fn index_mut(&mut self, idx: (usize, usize)) -> &mut f64 {
+        self.invalidate();
+        &mut self.inner[idx]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    fn sample() -> CachedMatrix {
+        Matrix::new(vec![vec![4.0, 7.0], vec![2.0, 6.0]])
+            .unwrap()
+            .into()
+    }
+
+    #[test]
+    fn it_should_reuse_cache_when_unchanged() {
+        let m = sample();
+        assert!(m.is_dirty());
+        assert_eq!(m.determinant().unwrap(), 10.0);
+        assert!(!m.is_dirty());
+        assert_eq!(m.determinant().unwrap(), 10.0);
+        let inv = m.inverse().unwrap();
+        assert_eq!(inv.data, m.inverse().unwrap().data);
+        assert!(!m.is_dirty());
+    }
+
+    #[test]
+    fn it_should_invalidate_cache_on_every_mutation() {
+        let mut m = sample();
+        m.determinant().unwrap();
+        m[(0, 0)] = 5.0;
+        assert!(m.is_dirty());
+        assert!((m.determinant().unwrap() - 16.0).abs() < 1e-12);
+
+        m.swap_rows(0, 1).unwrap();
+        assert!(m.is_dirty());
+        assert!((m.determinant().unwrap() - -16.0).abs() < 1e-12);
+
+        m.inverse().unwrap();
+        m.set_col(1, &[1.0, 1.0]).unwrap();
+        assert!(m.is_dirty());
+        assert!((m.determinant().unwrap() - -3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn it_should_not_invalidate_cache_on_reads() {
+        let m = sample();
+        m.determinant().unwrap();
+        let _ = m[(1, 1)];
+        let _ = m.matrix().trace();
+        assert!(!m.is_dirty());
+    }
+}
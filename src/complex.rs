@@ -0,0 +1,179 @@
+use crate::error::MathError;
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+use alloc::vec;
+use alloc::vec::Vec;
+use num_complex::Complex;
+use num_traits::{Float, Zero};
+
+pub type CVector<T = f64> = Vector<Complex<T>>;
+pub type CMatrix<T = f64> = Matrix<Complex<T>>;
+
+/// Inner products and norms for complex vectors. Element-wise `add`, `sub`,
+/// `mul` and `div` already work on `CVector` through the generic `Vector` impl
+pub trait ComplexVectorOps<T> {
+    /// Bilinear `Σ aᵢ·bᵢ` without conjugation
+    fn dot_product(&self, other: &Self) -> Result<Complex<T>, MathError>;
+    /// Conjugate-linear in `self`: `Σ conj(aᵢ)·bᵢ`, so `v.hermitian_dot(v)` is
+    /// real and equals `‖v‖²`
+    fn hermitian_dot(&self, other: &Self) -> Result<Complex<T>, MathError>;
+    /// Euclidean norm from the entries' magnitudes, `sqrt(Σ |aᵢ|²)`
+    fn norm(&self) -> Result<T, MathError>;
+}
+
+impl<T: Float> ComplexVectorOps<T> for CVector<T> {
+    fn dot_product(&self, other: &Self) -> Result<Complex<T>, MathError> {
+        if self.0.len() != other.0.len() {
+            return Err(MathError::ShapeMismatch(
+                "Vectors must have the same length",
+            ));
+        }
+        Ok(self
+            .0
+            .iter()
+            .zip(&other.0)
+            .fold(Complex::zero(), |acc, (&x, &y)| acc + x * y))
+    }
+
+    fn hermitian_dot(&self, other: &Self) -> Result<Complex<T>, MathError> {
+        if self.0.len() != other.0.len() {
+            return Err(MathError::ShapeMismatch(
+                "Vectors must have the same length",
+            ));
+        }
+        Ok(self
+            .0
+            .iter()
+            .zip(&other.0)
+            .fold(Complex::zero(), |acc, (&x, &y)| acc + x.conj() * y))
+    }
+
+    fn norm(&self) -> Result<T, MathError> {
+        if self.0.is_empty() {
+            return Err(MathError::Empty("Vector must have at least one element"));
+        }
+        Ok(self
+            .0
+            .iter()
+            .fold(T::zero(), |acc, x| acc + x.norm_sqr())
+            .sqrt())
+    }
+}
+
+/// Matrix operations for complex entries; the `Float`-bounded `Matrix`
+/// methods (decompositions, solvers) remain real-only
+pub trait ComplexMatrixOps<T>: Sized {
+    /// Transpose with every entry conjugated (`Aᴴ`)
+    fn conjugate_transpose(&self) -> Self;
+    fn mul(&self, other: &Self) -> Result<Self, MathError>;
+    fn mul_vec(&self, v: &[Complex<T>]) -> Result<Vec<Complex<T>>, MathError>;
+}
+
+impl<T: Float> ComplexMatrixOps<T> for CMatrix<T> {
+    fn conjugate_transpose(&self) -> Self {
+        Matrix {
+            data: (0..self.cols)
+                .map(|j| (0..self.rows).map(|i| self.data[i][j].conj()).collect())
+                .collect(),
+            rows: self.cols,
+            cols: self.rows,
+        }
+    }
+
+    fn mul(&self, other: &Self) -> Result<Self, MathError> {
+        if self.cols != other.rows {
+            return Err(MathError::ShapeMismatch(
+                "Incompatible shapes for multiplication",
+            ));
+        }
+        let mut out = vec![vec![Complex::zero(); other.cols]; self.rows];
+        for (out_row, row) in out.iter_mut().zip(&self.data) {
+            for (&a, other_row) in row.iter().zip(&other.data) {
+                for (o, &b) in out_row.iter_mut().zip(other_row) {
+                    *o = *o + a * b;
+                }
+            }
+        }
+        Ok(Matrix {
+            data: out,
+            rows: self.rows,
+            cols: other.cols,
+        })
+    }
+
+    fn mul_vec(&self, v: &[Complex<T>]) -> Result<Vec<Complex<T>>, MathError> {
+        if self.cols != v.len() {
+            return Err(MathError::ShapeMismatch(
+                "Incompatible shapes for matrix-vector multiplication",
+            ));
+        }
+        Ok(self
+            .data
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .zip(v)
+                    .fold(Complex::zero(), |acc, (&a, &b)| acc + a * b)
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn c(re: f64, im: f64) -> Complex<f64> {
+        Complex::new(re, im)
+    }
+
+    #[test]
+    fn it_should_compute_complex_dot_products() {
+        let a: CVector = Vector(vec![c(1.0, 2.0), c(0.0, -1.0)]);
+        let b: CVector = Vector(vec![c(3.0, 0.0), c(2.0, 1.0)]);
+        // (1+2i)·3 + (-i)(2+i) = 3+6i + 1-2i
+        assert_eq!(a.dot_product(&b).unwrap(), c(4.0, 4.0));
+        // (1-2i)·3 + (i)(2+i) = 3-6i + -1+2i
+        assert_eq!(a.hermitian_dot(&b).unwrap(), c(2.0, -4.0));
+        assert!(a.hermitian_dot(&Vector(vec![c(1.0, 0.0)])).is_err());
+    }
+
+    #[test]
+    fn it_should_give_real_squared_norm_from_hermitian_self_dot() {
+        let v: CVector = Vector(vec![c(3.0, 4.0), c(1.0, -1.0), c(0.0, 2.0)]);
+        let h = v.hermitian_dot(&v).unwrap();
+        assert_eq!(h.im, 0.0);
+        let norm = v.norm().unwrap();
+        assert!((norm - 31.0_f64.sqrt()).abs() < 1e-12);
+        assert!((h.re - norm * norm).abs() < 1e-12);
+    }
+
+    #[test]
+    fn it_should_reuse_generic_elementwise_ops() {
+        let a: CVector = Vector(vec![c(1.0, 1.0), c(2.0, 0.0)]);
+        let b: CVector = Vector(vec![c(0.0, 1.0), c(1.0, -1.0)]);
+        assert_eq!(a.add(&b).unwrap(), Vector(vec![c(1.0, 2.0), c(3.0, -1.0)]));
+        assert_eq!(a.mul(&b).unwrap(), Vector(vec![c(-1.0, 1.0), c(2.0, -2.0)]));
+    }
+
+    #[test]
+    fn it_should_multiply_complex_matrices() {
+        let a: CMatrix = Matrix::new(vec![
+            vec![c(1.0, 1.0), c(0.0, 2.0)],
+            vec![c(3.0, 0.0), c(1.0, -1.0)],
+        ])
+        .unwrap();
+        let ah = a.conjugate_transpose();
+        assert_eq!(ah.data[0][1], c(3.0, 0.0));
+        assert_eq!(ah.data[1][0], c(0.0, -2.0));
+
+        // Aᴴ·A is Hermitian with a real diagonal
+        let g = ah.mul(&a).unwrap();
+        assert_eq!(g.data[0][1], g.data[1][0].conj());
+        assert_eq!(g.data[0][0].im, 0.0);
+        assert_eq!(
+            a.mul_vec(&[c(1.0, 0.0), c(0.0, 1.0)]).unwrap(),
+            vec![c(-1.0, 1.0), c(4.0, 1.0)]
+        );
+    }
+}
@@ -8,6 +8,13 @@ pub enum VectorOp {
     Div,
 }
 
+/// Matrix axis: `Rows` indexes down a column, `Cols` along a row
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Axis {
+    Rows,
+    Cols,
+}
+
 // this will be used for another purpose later rsrsrs
 #[derive(Debug)]
 pub enum IpAddrKint {
@@ -0,0 +1,82 @@
+use core::fmt;
+
+/// Error type shared by the `Matrix` and `Vector` APIs. Variants carrying a
+/// message describe which precondition failed; `Display` prints that message
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MathError {
+    /// Operand lengths or matrix dimensions are incompatible
+    ShapeMismatch(&'static str),
+    NotSquare,
+    Singular,
+    /// An input that needs at least one element was empty
+    Empty(&'static str),
+    /// A zero divisor, including normalizing or projecting onto a zero vector
+    DivisionByZero,
+    /// An iterative method hit its iteration limit or broke down
+    NotConverged(&'static str),
+    IndexOutOfBounds(&'static str),
+    /// An argument is outside the domain the operation accepts
+    InvalidInput(&'static str),
+    /// A cell that is not a number, located by 1-based line and column
+    Parse {
+        line: usize,
+        column: usize,
+    },
+    /// Reading or writing a stream failed
+    #[cfg(feature = "std")]
+    Io(std::io::ErrorKind),
+}
+
+impl fmt::Display for MathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MathError::NotSquare => f.write_str("Matrix must be square"),
+            MathError::Singular => f.write_str("Matrix is singular"),
+            MathError::DivisionByZero => f.write_str("Division by zero"),
+            MathError::ShapeMismatch(msg)
+            | MathError::Empty(msg)
+            | MathError::NotConverged(msg)
+            | MathError::IndexOutOfBounds(msg)
+            | MathError::InvalidInput(msg) => f.write_str(msg),
+            MathError::Parse { line, column } => {
+                write!(f, "Invalid number at line {}, column {}", line, column)
+            }
+            #[cfg(feature = "std")]
+            MathError::Io(kind) => write!(f, "I/O error: {}", kind),
+        }
+    }
+}
+
+impl core::error::Error for MathError {}
+
+#[cfg(feature = "std")]
+impl From<std::io::Error> for MathError {
+    fn from(e: std::io::Error) -> Self {
+        MathError::Io(e.kind())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::boxed::Box;
+    use alloc::string::ToString;
+
+    #[test]
+    fn it_should_display_human_readable_messages() {
+        assert_eq!(MathError::NotSquare.to_string(), "Matrix must be square");
+        assert_eq!(MathError::Singular.to_string(), "Matrix is singular");
+        assert_eq!(MathError::DivisionByZero.to_string(), "Division by zero");
+        assert_eq!(
+            MathError::ShapeMismatch("Vectors must have the same length").to_string(),
+            "Vectors must have the same length"
+        );
+    }
+
+    #[test]
+    fn it_should_work_as_a_boxed_error() {
+        let err: Box<dyn core::error::Error> =
+            Box::new(MathError::NotConverged("GMRES did not converge"));
+        assert_eq!(err.to_string(), "GMRES did not converge");
+    }
+}
@@ -0,0 +1,86 @@
+use crate::error::MathError;
+use crate::matrix::Matrix;
+use num_traits::Float;
+use std::fmt::Display;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::str::FromStr;
+
+impl<T: Float + FromStr + Display> Matrix<T> {
+    /// Reads one matrix row per line of comma-separated numbers. Blank lines
+    /// are skipped; a cell that fails to parse reports its 1-based line and
+    /// column
+    pub fn from_csv(reader: impl Read) -> Result<Matrix<T>, MathError> {
+        let mut data = Vec::new();
+        for (i, line) in BufReader::new(reader).lines().enumerate() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let row = line
+                .split(',')
+                .enumerate()
+                .map(|(j, cell)| {
+                    cell.trim().parse().map_err(|_| MathError::Parse {
+                        line: i + 1,
+                        column: j + 1,
+                    })
+                })
+                .collect::<Result<Vec<T>, _>>()?;
+            data.push(row);
+        }
+        Matrix::new(data)
+    }
+
+    /// Writes one comma-separated line per row, using each value's `Display` form
+    pub fn to_csv(&self, mut writer: impl Write) -> Result<(), MathError> {
+        for row in &self.data {
+            let line = row
+                .iter()
+                .map(|x| x.to_string())
+                .collect::<Vec<_>>()
+                .join(",");
+            writeln!(writer, "{}", line)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_read_csv_into_matrix() {
+        let m: Matrix = Matrix::from_csv("1,2.5,-3\n4, 5 ,6e-1\n".as_bytes()).unwrap();
+        assert_eq!((m.rows, m.cols), (2, 3));
+        assert_eq!(m.data, vec![vec![1.0, 2.5, -3.0], vec![4.0, 5.0, 0.6]]);
+    }
+
+    #[test]
+    fn it_should_round_trip_csv() {
+        let csv = "1,2.5,-3\n0.125,1000,7\n";
+        let m: Matrix = Matrix::from_csv(csv.as_bytes()).unwrap();
+        let mut out = Vec::new();
+        m.to_csv(&mut out).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), csv);
+    }
+
+    #[test]
+    fn it_should_locate_malformed_csv_cells() {
+        let err = Matrix::<f64>::from_csv("1,2\n3,x\n".as_bytes()).unwrap_err();
+        assert_eq!(err, MathError::Parse { line: 2, column: 2 });
+        assert_eq!(err.to_string(), "Invalid number at line 2, column 2");
+    }
+
+    #[test]
+    fn it_should_reject_ragged_or_empty_csv() {
+        assert_eq!(
+            Matrix::<f64>::from_csv("1,2\n3\n".as_bytes()).unwrap_err(),
+            MathError::ShapeMismatch("All rows must have the same number of columns")
+        );
+        assert_eq!(
+            Matrix::<f64>::from_csv("".as_bytes()).unwrap_err(),
+            MathError::Empty("Matrix cannot be empty")
+        );
+    }
+}
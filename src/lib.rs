@@ -1,5 +1,20 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
 // pub mod vector;
+pub mod cached_matrix;
+pub mod complex;
 pub mod enums;
+pub mod error;
+#[cfg(feature = "std")]
+pub mod io;
 pub mod matrix;
+#[cfg(feature = "nalgebra")]
+mod nalgebra_interop;
+#[cfg(feature = "ndarray")]
+mod ndarray_interop;
 pub mod point;
+mod rng;
+pub mod sparse;
 pub mod vector;
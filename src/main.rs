@@ -1,7 +1,5 @@
 use vector_math::matrix::Matrix;
 
-mod vector;
-
 fn main() {
     let a = Matrix::new(vec![vec![1.0, 2.0], vec![3.0, 4.0], vec![5.0, 6.0]]).unwrap();
 
@@ -1,21 +1,37 @@
-use std::fmt;
+use crate::enums::Axis;
+use crate::error::MathError;
+use crate::rng::SplitMix64;
+use crate::vector::Vector;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+use core::ops::{self, Index, IndexMut, Range};
+use core::str::FromStr;
+use num_traits::Float;
 
-/// Simple Matrix type for numerical ops (f64)
+/// Simple Matrix type for numerical ops, generic over the float type (`f64` by default)
 #[derive(Debug, Clone)]
-pub struct Matrix {
-    pub data: Vec<Vec<f64>>,
+pub struct Matrix<T = f64> {
+    pub data: Vec<Vec<T>>,
     pub rows: usize,
     pub cols: usize,
 }
 
-impl Matrix {
-    pub fn new(data: Vec<Vec<f64>>) -> Result<Self, &'static str> {
+// Construction only validates the shape, so it is available for any element
+// type, including complex entries
+impl<T> Matrix<T> {
+    pub fn new(data: Vec<Vec<T>>) -> Result<Self, MathError> {
         if data.is_empty() || data[0].is_empty() {
-            return Err("Matrix cannot be empty");
+            return Err(MathError::Empty("Matrix cannot be empty"));
         }
         let cols = data[0].len();
         if !data.iter().all(|r| r.len() == cols) {
-            return Err("All rows must have the same number of columns");
+            return Err(MathError::ShapeMismatch(
+                "All rows must have the same number of columns",
+            ));
         }
         Ok(Matrix {
             rows: data.len(),
@@ -24,19 +40,140 @@ impl Matrix {
         })
     }
 
+    /// Lays out pre-rendered cells as one bracketed row per line, each column
+    /// right-aligned to at least `width` characters
+    fn align_cells(cells: &[Vec<String>], width: usize) -> String {
+        let cols = cells.first().map_or(0, Vec::len);
+        let widths: Vec<usize> = (0..cols)
+            .map(|j| cells.iter().map(|row| row[j].len()).fold(width, usize::max))
+            .collect();
+
+        let mut out = String::new();
+        for row in cells {
+            let row_str = row
+                .iter()
+                .zip(&widths)
+                .map(|(cell, &w)| format!("{:>w$}", cell, w = w))
+                .collect::<Vec<_>>()
+                .join(" ");
+            out.push_str(&format!("[{}]\n", row_str));
+        }
+        out
+    }
+}
+
+impl<T: Float> Matrix<T> {
+    pub fn from_flat(data: Vec<T>, rows: usize, cols: usize) -> Result<Self, MathError> {
+        if data.len() != rows * cols {
+            return Err(MathError::ShapeMismatch(
+                "Data length must equal rows * cols",
+            ));
+        }
+        if cols == 0 {
+            return Err(MathError::Empty("Matrix cannot be empty"));
+        }
+        Matrix::new(data.chunks(cols).map(|c| c.to_vec()).collect())
+    }
+
     pub fn zeros(rows: usize, cols: usize) -> Self {
+        Self::fill(rows, cols, T::zero())
+    }
+
+    pub fn fill(rows: usize, cols: usize, value: T) -> Self {
         Self {
-            data: vec![vec![0.0; cols]; rows],
+            data: vec![vec![value; cols]; rows],
             rows,
             cols,
         }
     }
 
-    pub fn transpose(&self) -> Matrix {
-        let mut t = vec![vec![0.0; self.rows]; self.cols];
-        for i in 0..self.rows {
-            for j in 0..self.cols {
-                t[j][i] = self.data[i][j];
+    pub fn from_fn<F>(rows: usize, cols: usize, f: F) -> Self
+    where
+        F: Fn(usize, usize) -> T,
+    {
+        Self {
+            data: (0..rows)
+                .map(|i| (0..cols).map(|j| f(i, j)).collect())
+                .collect(),
+            rows,
+            cols,
+        }
+    }
+
+    /// Entries drawn uniformly from `[0, 1)`; the same seed always yields the same matrix
+    pub fn random(rows: usize, cols: usize, seed: u64) -> Self {
+        let mut rng = SplitMix64::new(seed);
+        Self {
+            data: (0..rows)
+                .map(|_| {
+                    (0..cols)
+                        .map(|_| T::from(rng.next_f64()).unwrap())
+                        .collect()
+                })
+                .collect(),
+            rows,
+            cols,
+        }
+    }
+
+    pub fn set_all(&mut self, value: T) {
+        for row in self.data.iter_mut() {
+            row.fill(value);
+        }
+    }
+
+    pub fn identity(n: usize) -> Self {
+        let mut m = Self::zeros(n, n);
+        for i in 0..n {
+            m.data[i][i] = T::one();
+        }
+        m
+    }
+
+    /// Samples `f(x, y)` on a grid: entry `(i, j)` is `f(x[j], y[i])`, giving a
+    /// `y.len() × x.len()` matrix
+    pub fn from_function_grid<F>(x: &[T], y: &[T], f: F) -> Self
+    where
+        F: Fn(T, T) -> T,
+    {
+        Self {
+            data: y
+                .iter()
+                .map(|&yi| x.iter().map(|&xj| f(xj, yi)).collect())
+                .collect(),
+            rows: y.len(),
+            cols: x.len(),
+        }
+    }
+
+    pub fn from_diagonal(diag: &[T]) -> Self {
+        let mut m = Self::zeros(diag.len(), diag.len());
+        for (i, &d) in diag.iter().enumerate() {
+            m.data[i][i] = d;
+        }
+        m
+    }
+
+    pub fn diagonal(&self) -> Vec<T> {
+        (0..self.rows.min(self.cols))
+            .map(|i| self.data[i][i])
+            .collect()
+    }
+
+    /// Entries in row-major order
+    pub fn flatten(&self) -> Vec<T> {
+        self.data.iter().flatten().copied().collect()
+    }
+
+    pub fn reshape(&self, rows: usize, cols: usize) -> Result<Matrix<T>, MathError> {
+        Matrix::from_flat(self.flatten(), rows, cols)
+    }
+
+    pub fn transpose(&self) -> Matrix<T> {
+        let mut t = vec![vec![T::zero(); self.rows]; self.cols];
+        for (i, row) in self.data.iter().enumerate() {
+            for (j, &val) in row.iter().enumerate() {
+                t[j][i] = val;
             }
         }
         Matrix {
@@ -46,16 +183,72 @@ impl Matrix {
         }
     }
 
-    pub fn mul(&self, other: &Matrix) -> Result<Matrix, &'static str> {
+    fn zip_with(&self, other: &Matrix<T>, f: impl Fn(T, T) -> T) -> Result<Matrix<T>, MathError> {
+        if self.rows != other.rows || self.cols != other.cols {
+            return Err(MathError::ShapeMismatch("Matrix shapes must match"));
+        }
+        let data = self
+            .data
+            .iter()
+            .zip(&other.data)
+            .map(|(a, b)| a.iter().zip(b).map(|(&x, &y)| f(x, y)).collect())
+            .collect();
+        Ok(Matrix {
+            data,
+            rows: self.rows,
+            cols: self.cols,
+        })
+    }
+
+    pub fn add(&self, other: &Matrix<T>) -> Result<Matrix<T>, MathError> {
+        self.zip_with(other, |a, b| a + b)
+    }
+
+    pub fn sub(&self, other: &Matrix<T>) -> Result<Matrix<T>, MathError> {
+        self.zip_with(other, |a, b| a - b)
+    }
+
+    pub fn hadamard(&self, other: &Matrix<T>) -> Result<Matrix<T>, MathError> {
+        self.zip_with(other, |a, b| a * b)
+    }
+
+    pub fn hadamard_div(&self, other: &Matrix<T>) -> Result<Matrix<T>, MathError> {
+        if self.rows != other.rows || self.cols != other.cols {
+            return Err(MathError::ShapeMismatch("Matrix shapes must match"));
+        }
+        if other.data.iter().flatten().any(|&x| x == T::zero()) {
+            return Err(MathError::DivisionByZero);
+        }
+        self.zip_with(other, |a, b| a / b)
+    }
+
+    pub fn scale(&self, factor: T) -> Matrix<T> {
+        self.map(|x| x * factor)
+    }
+
+    pub fn map<F: Fn(T) -> T>(&self, f: F) -> Matrix<T> {
+        Matrix {
+            data: self
+                .data
+                .iter()
+                .map(|row| row.iter().map(|&x| f(x)).collect())
+                .collect(),
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+
+    pub fn mul(&self, other: &Matrix<T>) -> Result<Matrix<T>, MathError> {
         if self.cols != other.rows {
-            return Err("Incompatible shapes for multiplication");
+            return Err(MathError::ShapeMismatch(
+                "Incompatible shapes for multiplication",
+            ));
         }
-        let mut out = vec![vec![0.0; other.cols]; self.rows];
-        for i in 0..self.rows {
-            for k in 0..self.cols {
-                let a = self.data[i][k];
-                for j in 0..other.cols {
-                    out[i][j] += a * other.data[k][j];
+        let mut out = vec![vec![T::zero(); other.cols]; self.rows];
+        for (out_row, row) in out.iter_mut().zip(&self.data) {
+            for (&a, other_row) in row.iter().zip(&other.data) {
+                for (o, &b) in out_row.iter_mut().zip(other_row) {
+                    *o = *o + a * b;
                 }
             }
         }
@@ -66,24 +259,220 @@ impl Matrix {
         })
     }
 
-    pub fn mul_vec(&self, v: &[f64]) -> Result<Vec<f64>, &'static str> {
+    /// `mul` with the i/k/j loops tiled into `block`-sized chunks for cache
+    /// locality; `block == 0` picks a default of 64. Each entry accumulates its
+    /// terms in the same order as `mul`, so the results are identical
+    pub fn mul_blocked(&self, other: &Matrix<T>, block: usize) -> Result<Matrix<T>, MathError> {
+        if self.cols != other.rows {
+            return Err(MathError::ShapeMismatch(
+                "Incompatible shapes for multiplication",
+            ));
+        }
+        let bs = if block == 0 { 64 } else { block };
+        let (n, m, p) = (self.rows, self.cols, other.cols);
+        let mut out = Matrix::zeros(n, p);
+        for ii in (0..n).step_by(bs) {
+            for kk in (0..m).step_by(bs) {
+                for jj in (0..p).step_by(bs) {
+                    for i in ii..(ii + bs).min(n) {
+                        let out_row = &mut out.data[i];
+                        for k in kk..(kk + bs).min(m) {
+                            let a = self.data[i][k];
+                            let other_row = &other.data[k];
+                            for j in jj..(jj + bs).min(p) {
+                                out_row[j] = out_row[j] + a * other_row[j];
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        Ok(out)
+    }
+
+    /// Sums over `self_axis` of `self` and `other_axis` of `other`, so
+    /// `(Cols, Rows)` is `A·B`, `(Rows, Rows)` is `Aᵀ·B`, `(Cols, Cols)` is
+    /// `A·Bᵀ` and `(Rows, Cols)` is `Aᵀ·Bᵀ`, without materializing transposes
+    pub fn contract(
+        &self,
+        other: &Matrix<T>,
+        self_axis: Axis,
+        other_axis: Axis,
+    ) -> Result<Matrix<T>, MathError> {
+        let at = |m: &Matrix<T>, axis: Axis, free: usize, k: usize| match axis {
+            Axis::Rows => m.data[k][free],
+            Axis::Cols => m.data[free][k],
+        };
+        let (self_len, self_free) = match self_axis {
+            Axis::Rows => (self.rows, self.cols),
+            Axis::Cols => (self.cols, self.rows),
+        };
+        let (other_len, other_free) = match other_axis {
+            Axis::Rows => (other.rows, other.cols),
+            Axis::Cols => (other.cols, other.rows),
+        };
+        if self_len != other_len {
+            return Err(MathError::ShapeMismatch("Contracted dimensions must match"));
+        }
+
+        let mut out = Matrix::zeros(self_free, other_free);
+        for i in 0..self_free {
+            for j in 0..other_free {
+                out.data[i][j] = (0..self_len).fold(T::zero(), |acc, k| {
+                    acc + at(self, self_axis, i, k) * at(other, other_axis, j, k)
+                });
+            }
+        }
+        Ok(out)
+    }
+
+    /// `A^exp` by repeated squaring; `pow(0)` is the identity
+    pub fn pow(&self, exp: u32) -> Result<Matrix<T>, MathError> {
+        if self.rows != self.cols {
+            return Err(MathError::NotSquare);
+        }
+        let mut result = Matrix::identity(self.rows);
+        let mut base = self.clone();
+        let mut e = exp;
+        while e > 0 {
+            if e & 1 == 1 {
+                result = result.mul(&base)?;
+            }
+            e >>= 1;
+            if e > 0 {
+                base = base.mul(&base)?;
+            }
+        }
+        Ok(result)
+    }
+
+    pub fn mul_vec(&self, v: &[T]) -> Result<Vec<T>, MathError> {
         if self.cols != v.len() {
-            return Err("Incompatible shapes for matrix-vector multiplication");
+            return Err(MathError::ShapeMismatch(
+                "Incompatible shapes for matrix-vector multiplication",
+            ));
         }
-        Ok(self
-            .data
+        Ok(self.data.iter().map(|row| Self::dot(row, v)).collect())
+    }
+
+    pub fn quadratic_form(&self, x: &[T]) -> Result<T, MathError> {
+        if self.rows != self.cols {
+            return Err(MathError::NotSquare);
+        }
+        let ax = self.mul_vec(x)?;
+        Ok(Self::dot(x, &ax))
+    }
+
+    /// Evaluates `xᵀ A x` for every point, validating all lengths up front
+    pub fn quadratic_form_batch(&self, points: &[Vec<T>]) -> Result<Vec<T>, MathError> {
+        if self.rows != self.cols {
+            return Err(MathError::NotSquare);
+        }
+        if points.iter().any(|p| p.len() != self.rows) {
+            return Err(MathError::ShapeMismatch(
+                "Incompatible shapes for matrix-vector multiplication",
+            ));
+        }
+        Ok(points
             .iter()
-            .map(|row| row.iter().zip(v).map(|(a, b)| a * b).sum())
+            .map(|x| {
+                let ax = Self::mat_vec_mul(&self.data, x);
+                Self::dot(x, &ax)
+            })
             .collect())
     }
 
-    pub fn col(&self, idx: usize) -> Vec<f64> {
+    pub fn trace(&self) -> Result<T, MathError> {
+        if self.rows != self.cols {
+            return Err(MathError::NotSquare);
+        }
+        Ok((0..self.rows).fold(T::zero(), |acc, i| acc + self.data[i][i]))
+    }
+
+    /// `tr(A·B) = Σᵢⱼ aᵢⱼ·bⱼᵢ` without allocating the product
+    pub fn trace_of_product(&self, other: &Matrix<T>) -> Result<T, MathError> {
+        if self.cols != other.rows {
+            return Err(MathError::ShapeMismatch(
+                "Incompatible shapes for multiplication",
+            ));
+        }
+        if self.rows != other.cols {
+            return Err(MathError::NotSquare);
+        }
+        Ok((0..self.rows).fold(T::zero(), |acc, i| {
+            (0..self.cols).fold(acc, |acc, j| acc + self.data[i][j] * other.data[j][i])
+        }))
+    }
+
+    pub fn submatrix(
+        &self,
+        rows: Range<usize>,
+        cols: Range<usize>,
+    ) -> Result<Matrix<T>, MathError> {
+        if rows.end > self.rows
+            || cols.end > self.cols
+            || rows.start > rows.end
+            || cols.start > cols.end
+        {
+            return Err(MathError::IndexOutOfBounds("Submatrix range out of bounds"));
+        }
+        Matrix::new(
+            self.data[rows]
+                .iter()
+                .map(|row| row[cols.clone()].to_vec())
+                .collect(),
+        )
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &Vec<T>> {
+        self.data.iter()
+    }
+
+    /// Columns are not contiguous in the row-major storage, so each item is a
+    /// freshly allocated copy of the column
+    pub fn cols(&self) -> impl Iterator<Item = Vec<T>> + '_ {
+        (0..self.cols).map(|j| self.col(j))
+    }
+
+    pub fn hstack(&self, other: &Matrix<T>) -> Result<Matrix<T>, MathError> {
+        if self.rows != other.rows {
+            return Err(MathError::ShapeMismatch(
+                "Matrices must have the same number of rows",
+            ));
+        }
+        let data = self
+            .data
+            .iter()
+            .zip(&other.data)
+            .map(|(a, b)| a.iter().chain(b).copied().collect())
+            .collect();
+        Ok(Matrix {
+            data,
+            rows: self.rows,
+            cols: self.cols + other.cols,
+        })
+    }
+
+    pub fn vstack(&self, other: &Matrix<T>) -> Result<Matrix<T>, MathError> {
+        if self.cols != other.cols {
+            return Err(MathError::ShapeMismatch(
+                "Matrices must have the same number of columns",
+            ));
+        }
+        Ok(Matrix {
+            data: self.data.iter().chain(&other.data).cloned().collect(),
+            rows: self.rows + other.rows,
+            cols: self.cols,
+        })
+    }
+
+    pub fn col(&self, idx: usize) -> Vec<T> {
         (0..self.rows).map(|r| self.data[r][idx]).collect()
     }
 
-    pub fn set_col(&mut self, idx: usize, col: &[f64]) -> Result<(), &'static str> {
+    pub fn set_col(&mut self, idx: usize, col: &[T]) -> Result<(), MathError> {
         if col.len() != self.rows {
-            return Err("Column length mismatch");
+            return Err(MathError::ShapeMismatch("Column length mismatch"));
         }
         for (r, val) in col.iter().enumerate() {
             self.data[r][idx] = *val;
@@ -91,78 +480,138 @@ impl Matrix {
         Ok(())
     }
 
-    fn normalize(v: &mut Vec<f64>) -> f64 {
-        let norm = v.iter().map(|x| x * x).sum::<f64>().sqrt();
-        if norm > 0.0 {
+    pub fn swap_rows(&mut self, i: usize, j: usize) -> Result<(), MathError> {
+        if i >= self.rows || j >= self.rows {
+            return Err(MathError::IndexOutOfBounds("Row index out of bounds"));
+        }
+        self.data.swap(i, j);
+        Ok(())
+    }
+
+    pub fn swap_cols(&mut self, i: usize, j: usize) -> Result<(), MathError> {
+        if i >= self.cols || j >= self.cols {
+            return Err(MathError::IndexOutOfBounds("Column index out of bounds"));
+        }
+        for row in self.data.iter_mut() {
+            row.swap(i, j);
+        }
+        Ok(())
+    }
+
+    fn dot(u: &[T], v: &[T]) -> T {
+        u.iter().zip(v).fold(T::zero(), |acc, (&a, &b)| acc + a * b)
+    }
+
+    fn sum(v: &[T]) -> T {
+        v.iter().fold(T::zero(), |acc, &x| acc + x)
+    }
+
+    fn cmp_abs(a: T, b: T) -> Ordering {
+        a.abs().partial_cmp(&b.abs()).unwrap_or(Ordering::Equal)
+    }
+
+    fn normalize(v: &mut [T]) -> T {
+        let norm = Self::dot(v, v).sqrt();
+        if norm > T::zero() {
             for val in v.iter_mut() {
-                *val /= norm;
+                *val = *val / norm;
             }
         }
         norm
     }
 
-    fn mat_vec_mul(m: &[Vec<f64>], v: &[f64]) -> Vec<f64> {
-        m.iter()
-            .map(|row| row.iter().zip(v).map(|(a, b)| a * b).sum())
-            .collect()
+    fn mat_vec_mul(m: &[Vec<T>], v: &[T]) -> Vec<T> {
+        m.iter().map(|row| Self::dot(row, v)).collect()
     }
 
-    fn rayleigh_quotient(m: &[Vec<f64>], v: &[f64]) -> f64 {
-        let mv = Self::mat_vec_mul(m, v);
-        v.iter().zip(mv.iter()).map(|(vi, mvi)| vi * mvi).sum()
+    fn rayleigh_quotient(m: &[Vec<T>], v: &[T]) -> T {
+        Self::dot(v, &Self::mat_vec_mul(m, v))
     }
 
-    pub fn svd(
-        &self,
-        tol: f64,
-        max_iter: usize,
-    ) -> Result<(Matrix, Vec<f64>, Matrix), &'static str> {
+    pub fn svd(&self, tol: T, max_iter: usize) -> Result<(Self, Vec<T>, Self), MathError> {
         let ata = self.transpose().mul(self)?;
         let n = ata.rows;
         let mut ata_work = ata.data.clone();
 
         let mut eigvals = Vec::new();
         let mut eigvecs = Vec::new();
+        // A fixed all-ones start vector is an exact eigenvector whenever AᵀA = cI,
+        // which would deflate everything after the first component
+        let mut rng = SplitMix64::new(0x5EED);
 
         for _ in 0..n {
-            let mut b_k = vec![1.0; n];
-            if Self::normalize(&mut b_k) == 0.0 {
+            let mut b_k: Vec<T> = (0..n)
+                .map(|_| T::from(rng.next_f64() - 0.5).unwrap())
+                .collect();
+            if Self::normalize(&mut b_k) == T::zero() {
                 break;
             }
 
-            let mut lambda = 0.0;
+            let mut lambda = T::zero();
+            let mut converged = false;
             for _ in 0..max_iter {
                 let mut b_next = Self::mat_vec_mul(&ata_work, &b_k);
-                if Self::normalize(&mut b_next) == 0.0 {
+                if Self::normalize(&mut b_next) == T::zero() {
+                    // Deflated matrix is exactly zero: no components remain
+                    converged = true;
                     break;
                 }
                 let lambda_next = Self::rayleigh_quotient(&ata_work, &b_next);
-                if (lambda_next - lambda).abs() < tol {
+                // The eigenvalue settles long before the vector when the leading
+                // eigenvalues are close, so require both to have converged. The
+                // eigenvalue test is relative so large values can reach `tol`
+                let step = b_next
+                    .iter()
+                    .zip(&b_k)
+                    .fold(T::zero(), |acc, (&x, &y)| acc + (x - y) * (x - y))
+                    .sqrt();
+                if (lambda_next - lambda).abs() < tol * lambda_next.abs().max(T::one())
+                    && step < tol
+                {
                     lambda = lambda_next;
                     b_k = b_next;
+                    converged = true;
                     break;
                 }
                 lambda = lambda_next;
                 b_k = b_next;
             }
 
-            if lambda.abs() < 1e-12 {
+            if lambda.abs() < T::from(1e-12).unwrap() {
                 break;
             }
+            // Dropping or keeping a half-converged component would silently
+            // corrupt every later one after deflation
+            if !converged {
+                return Err(MathError::NotConverged(
+                    "SVD did not converge within max_iter",
+                ));
+            }
 
             eigvals.push(lambda);
             eigvecs.push(b_k.clone());
 
             for i in 0..n {
                 for j in 0..n {
-                    ata_work[i][j] -= lambda * b_k[i] * b_k[j];
+                    ata_work[i][j] = ata_work[i][j] - lambda * b_k[i] * b_k[j];
                 }
             }
         }
 
-        let singular_values: Vec<f64> = eigvals
+        // Deflation can occasionally surface a smaller component first; ordering
+        // the eigenpairs here keeps U's columns and Vᵀ's rows aligned with it
+        let mut order: Vec<usize> = (0..eigvals.len()).collect();
+        order.sort_by(|&i, &j| {
+            eigvals[j]
+                .partial_cmp(&eigvals[i])
+                .unwrap_or(Ordering::Equal)
+        });
+        let eigvecs: Vec<Vec<T>> = order.iter().map(|&i| eigvecs[i].clone()).collect();
+        let eigvals: Vec<T> = order.iter().map(|&i| eigvals[i]).collect();
+
+        let singular_values: Vec<T> = eigvals
             .iter()
-            .map(|&x| if x > 0.0 { x.sqrt() } else { 0.0 })
+            .map(|&x| if x > T::zero() { x.sqrt() } else { T::zero() })
             .collect();
         let r = singular_values.len();
         if r == 0 {
@@ -182,10 +631,10 @@ impl Matrix {
         let mut u_mat = Matrix::zeros(self.rows, r);
         for (j, sigma) in singular_values.iter().enumerate() {
             let col_aj = av.col(j);
-            let col = if sigma.abs() < 1e-12 {
-                vec![0.0; self.rows]
+            let col = if sigma.abs() < T::from(1e-12).unwrap() {
+                vec![T::zero(); self.rows]
             } else {
-                col_aj.iter().map(|x| x / sigma).collect()
+                col_aj.iter().map(|&x| x / *sigma).collect()
             };
             u_mat.set_col(j, &col)?;
         }
@@ -193,26 +642,3822 @@ impl Matrix {
         // Build V^T
         let mut v_t = Matrix::zeros(r, n);
         for j in 0..r {
-            let vj = v_mat.col(j);
-            for i in 0..n {
-                v_t.data[j][i] = vj[i];
-            }
+            v_t.data[j].copy_from_slice(&v_mat.col(j));
         }
 
         Ok((u_mat, singular_values, v_t))
     }
-}
 
-impl fmt::Display for Matrix {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        for row in &self.data {
-            let row_str = row
-                .iter()
-                .map(|x| format!("{:8.4}", x))
-                .collect::<Vec<_>>()
-                .join(" ");
-            writeln!(f, "[{}]", row_str)?;
+    /// LU factorization with partial pivoting: `P·A = L·U`, where row `i` of
+    /// `P·A` is row `perm[i]` of `A` and `L` has a unit diagonal
+    pub fn lu(&self) -> Result<(Self, Self, Vec<usize>), MathError> {
+        if self.rows != self.cols {
+            return Err(MathError::NotSquare);
+        }
+        let n = self.rows;
+        let mut u = self.clone();
+        let mut l = Matrix::identity(n);
+        let mut perm: Vec<usize> = (0..n).collect();
+        for k in 0..n {
+            let p = (k..n)
+                .max_by(|&i, &j| Self::cmp_abs(u.data[i][k], u.data[j][k]))
+                .unwrap();
+            if p != k {
+                u.data.swap(k, p);
+                perm.swap(k, p);
+                for j in 0..k {
+                    let tmp = l.data[k][j];
+                    l.data[k][j] = l.data[p][j];
+                    l.data[p][j] = tmp;
+                }
+            }
+            if u.data[k][k].abs() < T::from(1e-12).unwrap() {
+                continue;
+            }
+            for i in k + 1..n {
+                let factor = u.data[i][k] / u.data[k][k];
+                l.data[i][k] = factor;
+                for j in k..n {
+                    u.data[i][j] = u.data[i][j] - factor * u.data[k][j];
+                }
+            }
+        }
+        Ok((l, u, perm))
+    }
+
+    /// `lu` plus the parity of `perm`: `1` for an even number of row swaps,
+    /// `-1` for odd, so `det(A) = parity·Π uᵢᵢ`
+    pub fn lu_parity(&self) -> Result<(Self, Self, Vec<usize>, i8), MathError> {
+        let (l, u, perm) = self.lu()?;
+        // Each cycle of length k in the permutation contributes k - 1 swaps
+        let mut visited = vec![false; perm.len()];
+        let mut parity = 1;
+        for start in 0..perm.len() {
+            let mut i = start;
+            while !visited[i] {
+                visited[i] = true;
+                i = perm[i];
+                if i != start {
+                    parity = -parity;
+                }
+            }
+        }
+        Ok((l, u, perm, parity))
+    }
+
+    /// Solves `A·x = b` for square `A` through its LU factorization
+    pub fn solve(&self, b: &[T]) -> Result<Vec<T>, MathError> {
+        if self.rows != self.cols {
+            return Err(MathError::NotSquare);
+        }
+        if b.len() != self.rows {
+            return Err(MathError::ShapeMismatch("Right-hand side length mismatch"));
+        }
+        let (l, u, perm) = self.lu()?;
+        if (0..self.rows).any(|i| u.data[i][i].abs() < T::from(1e-12).unwrap()) {
+            return Err(MathError::Singular);
+        }
+        Ok(Self::lu_solve(&l, &u, &perm, b))
+    }
+
+    /// Least-squares solution of `min ‖A·x - b‖` from the normal equations
+    /// `AᵀA·x = Aᵀb`. Forming `AᵀA` squares the condition number, so this is
+    /// less stable than a QR-based solve for ill-conditioned `A`
+    pub fn lstsq_normal(&self, b: &[T]) -> Result<Vec<T>, MathError> {
+        if b.len() != self.rows {
+            return Err(MathError::ShapeMismatch("Right-hand side length mismatch"));
+        }
+        let at = self.transpose();
+        at.mul(self)?.solve(&at.mul_vec(b)?)
+    }
+
+    /// Least-squares solution of `min ‖A·x - b‖` by solving `R·x = Qᵀb` from the
+    /// QR factorization. Avoids forming `AᵀA`, so it stays accurate where
+    /// `lstsq_normal` loses digits. Requires rows >= cols and full column rank
+    pub fn lstsq_qr(&self, b: &[T]) -> Result<Vec<T>, MathError> {
+        if b.len() != self.rows {
+            return Err(MathError::ShapeMismatch("Right-hand side length mismatch"));
+        }
+        let (q, r) = self.qr()?;
+        let n = self.cols;
+        let r1 = Matrix::from_fn(n, n, |i, j| r.data[i][j]);
+        let scale = r1.diagonal().iter().fold(T::zero(), |m, &d| m.max(d.abs()));
+        if r1
+            .diagonal()
+            .iter()
+            .any(|d| d.abs() <= T::epsilon() * T::from(self.rows).unwrap() * scale)
+        {
+            return Err(MathError::InvalidInput("Matrix must have full column rank"));
+        }
+        let qtb = q.transpose().mul_vec(b)?;
+        Ok(r1.back_sub(&qtb[..n]))
+    }
+
+    /// Ridge (Tikhonov) regression: solves `(AᵀA + λI)·x = Aᵀb`. Any `λ > 0`
+    /// makes the system positive definite, so rank-deficient `A` is fine;
+    /// `λ = 0` reduces to `lstsq_normal`
+    pub fn ridge(&self, b: &[T], lambda: T) -> Result<Vec<T>, MathError> {
+        if lambda < T::zero() {
+            return Err(MathError::InvalidInput(
+                "Regularization parameter must be non-negative",
+            ));
+        }
+        if b.len() != self.rows {
+            return Err(MathError::ShapeMismatch("Right-hand side length mismatch"));
+        }
+        let at = self.transpose();
+        let mut ata = at.mul(self)?;
+        for i in 0..ata.rows {
+            ata.data[i][i] = ata.data[i][i] + lambda;
+        }
+        ata.solve(&at.mul_vec(b)?)
+    }
+
+    /// Inverse via one LU factorization and a solve per identity column
+    pub fn inverse(&self) -> Result<Matrix<T>, MathError> {
+        let (l, u, perm) = self.lu()?;
+        let n = self.rows;
+        if (0..n).any(|i| u.data[i][i].abs() < T::from(1e-12).unwrap()) {
+            return Err(MathError::Singular);
+        }
+        let mut inv = Matrix::zeros(n, n);
+        let mut e = vec![T::zero(); n];
+        for j in 0..n {
+            e[j] = T::one();
+            inv.set_col(j, &Self::lu_solve(&l, &u, &perm, &e))?;
+            e[j] = T::zero();
+        }
+        Ok(inv)
+    }
+
+    /// False for non-square matrices and when any LU pivot has magnitude at
+    /// most `tol`, i.e. whenever `inverse` would be unreliable
+    pub fn is_invertible(&self, tol: T) -> bool {
+        match self.lu() {
+            Ok((_, u, _)) => (0..self.rows).all(|i| u.data[i][i].abs() > tol),
+            Err(_) => false,
+        }
+    }
+
+    fn lu_solve(l: &Matrix<T>, u: &Matrix<T>, perm: &[usize], b: &[T]) -> Vec<T> {
+        let pb: Vec<T> = perm.iter().map(|&p| b[p]).collect();
+        u.back_sub(&l.forward_sub(&pb))
+    }
+
+    /// Solves `L·x = b` by forward substitution, reading only the lower triangle
+    pub fn solve_lower(&self, b: &[T]) -> Result<Vec<T>, MathError> {
+        self.check_triangular_system(b)?;
+        Ok(self.forward_sub(b))
+    }
+
+    /// Solves `U·x = b` by back substitution, reading only the upper triangle
+    pub fn solve_upper(&self, b: &[T]) -> Result<Vec<T>, MathError> {
+        self.check_triangular_system(b)?;
+        Ok(self.back_sub(b))
+    }
+
+    fn check_triangular_system(&self, b: &[T]) -> Result<(), MathError> {
+        if self.rows != self.cols {
+            return Err(MathError::NotSquare);
+        }
+        if b.len() != self.rows {
+            return Err(MathError::ShapeMismatch("Right-hand side length mismatch"));
+        }
+        if self.diagonal().contains(&T::zero()) {
+            return Err(MathError::Singular);
+        }
+        Ok(())
+    }
+
+    fn forward_sub(&self, b: &[T]) -> Vec<T> {
+        let n = self.rows;
+        let mut x = vec![T::zero(); n];
+        for i in 0..n {
+            let s = (0..i).fold(T::zero(), |acc, j| acc + self.data[i][j] * x[j]);
+            x[i] = (b[i] - s) / self.data[i][i];
+        }
+        x
+    }
+
+    fn back_sub(&self, b: &[T]) -> Vec<T> {
+        let n = self.rows;
+        let mut x = vec![T::zero(); n];
+        for i in (0..n).rev() {
+            let s = (i + 1..n).fold(T::zero(), |acc, j| acc + self.data[i][j] * x[j]);
+            x[i] = (b[i] - s) / self.data[i][i];
+        }
+        x
+    }
+
+    /// Reflection `I - 2·v·vᵀ / (vᵀ·v)` across the hyperplane orthogonal to `v`
+    pub fn householder(v: &[T]) -> Result<Matrix<T>, MathError> {
+        let vv = Self::dot(v, v);
+        if vv == T::zero() {
+            return Err(MathError::DivisionByZero);
+        }
+        let two = T::from(2.0).unwrap();
+        let n = v.len();
+        Ok(Matrix::from_fn(n, n, |i, j| {
+            let id = if i == j { T::one() } else { T::zero() };
+            id - two * v[i] * v[j] / vv
+        }))
+    }
+
+    /// n×n rotation by `theta` in the `(i, j)` plane: `(G·x)ᵢ = c·xᵢ - s·xⱼ`
+    /// and `(G·x)ⱼ = s·xᵢ + c·xⱼ`. `theta = atan2(-xⱼ, xᵢ)` zeroes `xⱼ`
+    pub fn givens(n: usize, i: usize, j: usize, theta: T) -> Result<Matrix<T>, MathError> {
+        if i >= n || j >= n {
+            return Err(MathError::IndexOutOfBounds("Index out of bounds"));
+        }
+        if i == j {
+            return Err(MathError::InvalidInput(
+                "Rotation plane indices must differ",
+            ));
+        }
+        let (s, c) = theta.sin_cos();
+        let mut g = Matrix::identity(n);
+        g.data[i][i] = c;
+        g.data[j][j] = c;
+        g.data[i][j] = -s;
+        g.data[j][i] = s;
+        Ok(g)
+    }
+
+    /// Permutation matrix `P` with `(P·A)` row `i` equal to `A` row `perm[i]`,
+    /// the same convention as the permutation returned by `lu`
+    pub fn permutation(perm: &[usize]) -> Result<Matrix<T>, MathError> {
+        Self::check_permutation(perm)?;
+        let n = perm.len();
+        Ok(Matrix::from_fn(n, n, |i, j| {
+            if perm[i] == j { T::one() } else { T::zero() }
+        }))
+    }
+
+    /// Computes `P·A` for `P = Matrix::permutation(perm)` by reordering rows
+    pub fn permute_rows(&self, perm: &[usize]) -> Result<Matrix<T>, MathError> {
+        if perm.len() != self.rows {
+            return Err(MathError::ShapeMismatch(
+                "Permutation length must equal number of rows",
+            ));
+        }
+        Self::check_permutation(perm)?;
+        Ok(Matrix {
+            data: perm.iter().map(|&p| self.data[p].clone()).collect(),
+            rows: self.rows,
+            cols: self.cols,
+        })
+    }
+
+    fn check_permutation(perm: &[usize]) -> Result<(), MathError> {
+        let mut seen = vec![false; perm.len()];
+        for &p in perm {
+            if p >= perm.len() || seen[p] {
+                return Err(MathError::InvalidInput(
+                    "Index array must be a permutation of 0..n",
+                ));
+            }
+            seen[p] = true;
         }
         Ok(())
     }
+
+    /// Householder QR: returns orthogonal `Q` (rows×rows) and upper-triangular `R` (rows×cols)
+    pub fn qr(&self) -> Result<(Matrix<T>, Matrix<T>), MathError> {
+        if self.rows < self.cols {
+            return Err(MathError::InvalidInput("QR requires rows >= cols"));
+        }
+        let m = self.rows;
+        let two = T::from(2.0).unwrap();
+        let mut r = self.clone();
+        let mut q = Matrix::identity(m);
+
+        for k in 0..self.cols.min(m - 1) {
+            let mut v: Vec<T> = (k..m).map(|i| r.data[i][k]).collect();
+            let alpha = Self::dot(&v, &v).sqrt();
+            if alpha < T::from(1e-15).unwrap() {
+                continue;
+            }
+            // Reflect onto -sign(x0)·‖x‖·e1 to avoid cancellation
+            v[0] = v[0] + if v[0] >= T::zero() { alpha } else { -alpha };
+            if Self::normalize(&mut v) == T::zero() {
+                continue;
+            }
+
+            // R <- H R, applied to rows k..m
+            for j in 0..self.cols {
+                let dot = (k..m).fold(T::zero(), |acc, i| acc + v[i - k] * r.data[i][j]);
+                for i in k..m {
+                    r.data[i][j] = r.data[i][j] - two * v[i - k] * dot;
+                }
+            }
+            // Q <- Q H, applied to columns k..m
+            for row in q.data.iter_mut() {
+                let dot = (k..m).fold(T::zero(), |acc, j| acc + row[j] * v[j - k]);
+                for j in k..m {
+                    row[j] = row[j] - two * dot * v[j - k];
+                }
+            }
+        }
+
+        // Clean the round-off left below the diagonal
+        for i in 0..m {
+            for j in 0..i.min(self.cols) {
+                r.data[i][j] = T::zero();
+            }
+        }
+        Ok((q, r))
+    }
+
+    pub fn is_symmetric(&self, tol: T) -> bool {
+        self.rows == self.cols
+            && (0..self.rows)
+                .all(|i| (0..i).all(|j| (self.data[i][j] - self.data[j][i]).abs() <= tol))
+    }
+
+    /// Square with every off-diagonal entry within `tol` of zero
+    pub fn is_diagonal(&self, tol: T) -> bool {
+        self.is_upper_triangular(tol) && self.is_lower_triangular(tol)
+    }
+
+    /// Square with every entry below the diagonal within `tol` of zero
+    pub fn is_upper_triangular(&self, tol: T) -> bool {
+        self.rows == self.cols
+            && (0..self.rows).all(|i| (0..i).all(|j| self.data[i][j].abs() <= tol))
+    }
+
+    /// Square with every entry above the diagonal within `tol` of zero
+    pub fn is_lower_triangular(&self, tol: T) -> bool {
+        self.rows == self.cols
+            && (0..self.rows).all(|i| (i + 1..self.cols).all(|j| self.data[i][j].abs() <= tol))
+    }
+
+    /// Entry-wise comparison within `tol`; differing shapes are never equal
+    pub fn approx_eq(&self, other: &Matrix<T>, tol: T) -> bool {
+        self.rows == other.rows
+            && self.cols == other.cols
+            && self
+                .data
+                .iter()
+                .zip(&other.data)
+                .all(|(a, b)| a.iter().zip(b).all(|(&x, &y)| (x - y).abs() <= tol))
+    }
+
+    /// Checks `Aᵀ·A ≈ I` entry-wise within `tol`
+    pub fn is_orthogonal(&self, tol: T) -> bool {
+        if self.rows != self.cols {
+            return false;
+        }
+        let ata = self.contract(self, Axis::Rows, Axis::Rows).unwrap();
+        ata.data.iter().enumerate().all(|(i, row)| {
+            row.iter()
+                .enumerate()
+                .all(|(j, &x)| (x - if i == j { T::one() } else { T::zero() }).abs() <= tol)
+        })
+    }
+
+    /// Lower-triangular `L` with `A = L·Lᵀ` for symmetric positive-definite `A`
+    pub fn cholesky(&self) -> Result<Matrix<T>, MathError> {
+        if self.rows != self.cols {
+            return Err(MathError::NotSquare);
+        }
+        if !self.is_symmetric(T::from(1e-10).unwrap()) {
+            return Err(MathError::InvalidInput("Matrix must be symmetric"));
+        }
+        let n = self.rows;
+
+        let mut l = Matrix::zeros(n, n);
+        for i in 0..n {
+            for j in 0..=i {
+                let s = (0..j).fold(T::zero(), |acc, k| acc + l.data[i][k] * l.data[j][k]);
+                if i == j {
+                    let pivot = self.data[i][i] - s;
+                    if pivot <= T::zero() {
+                        return Err(MathError::InvalidInput("Matrix is not positive definite"));
+                    }
+                    l.data[i][j] = pivot.sqrt();
+                } else {
+                    l.data[i][j] = (self.data[i][j] - s) / l.data[j][j];
+                }
+            }
+        }
+        Ok(l)
+    }
+
+    pub fn is_row_stochastic(&self, tol: T) -> bool {
+        self.data.iter().all(|row| {
+            row.iter().all(|&x| x >= T::zero()) && (Self::sum(row) - T::one()).abs() <= tol
+        })
+    }
+
+    pub fn to_row_stochastic(&self) -> Result<Matrix<T>, MathError> {
+        let mut out = self.clone();
+        for row in out.data.iter_mut() {
+            if row.iter().any(|&x| x < T::zero()) {
+                return Err(MathError::InvalidInput(
+                    "Matrix entries must be non-negative",
+                ));
+            }
+            let total = Self::sum(row);
+            if total == T::zero() {
+                return Err(MathError::DivisionByZero);
+            }
+            for x in row.iter_mut() {
+                *x = *x / total;
+            }
+        }
+        Ok(out)
+    }
+
+    /// Steady-state distribution `π` with `π P = π` of a row-stochastic matrix.
+    /// `tol` bounds both the change between iterates and how far row sums may
+    /// stray from 1, never below the round-off bound `2n·ε` so a tight `tol`
+    /// does not reject `P`
+    pub fn stationary_distribution(&self, tol: T, max_iter: usize) -> Result<Vec<T>, MathError> {
+        if self.rows != self.cols {
+            return Err(MathError::NotSquare);
+        }
+        let row_sum_tol = tol.max(T::epsilon() * T::from(2 * self.cols).unwrap());
+        if !self.is_row_stochastic(row_sum_tol) {
+            return Err(MathError::InvalidInput("Matrix must be row-stochastic"));
+        }
+        let n = self.rows;
+        let half = T::from(0.5).unwrap();
+        let mut pi = vec![T::one() / T::from(n).unwrap(); n];
+        for _ in 0..max_iter {
+            // Iterate the lazy chain (P + I) / 2: same fixed point, but never periodic
+            let mut next = vec![T::zero(); n];
+            for (i, row) in self.data.iter().enumerate() {
+                for (j, &p) in row.iter().enumerate() {
+                    next[j] = next[j] + half * pi[i] * p;
+                }
+                next[i] = next[i] + half * pi[i];
+            }
+            let total = Self::sum(&next);
+            next.iter_mut().for_each(|x| *x = *x / total);
+
+            let diff = next
+                .iter()
+                .zip(&pi)
+                .fold(T::zero(), |acc, (&a, &b)| acc + (a - b).abs());
+            pi = next;
+            if diff < tol {
+                return Ok(pi);
+            }
+        }
+        Err(MathError::NotConverged(
+            "Stationary distribution did not converge",
+        ))
+    }
+
+    pub fn kron(&self, other: &Matrix<T>) -> Matrix<T> {
+        let mut out = Matrix::zeros(self.rows * other.rows, self.cols * other.cols);
+        for (i, row) in self.data.iter().enumerate() {
+            for (j, &a) in row.iter().enumerate() {
+                for (k, other_row) in other.data.iter().enumerate() {
+                    for (l, &b) in other_row.iter().enumerate() {
+                        out.data[i * other.rows + k][j * other.cols + l] = a * b;
+                    }
+                }
+            }
+        }
+        out
+    }
+
+    /// Entropy-based effective rank `exp(-Σ pᵢ ln pᵢ)` with `pᵢ = σᵢ / Σσ`
+    pub fn effective_rank(&self, tol: T, max_iter: usize) -> Result<T, MathError> {
+        let (_, s, _) = self.svd(tol, max_iter)?;
+        let total = Self::sum(&s);
+        if total == T::zero() {
+            return Ok(T::zero());
+        }
+        let entropy = s
+            .iter()
+            .map(|&x| x / total)
+            .filter(|&p| p > T::zero())
+            .fold(T::zero(), |acc, p| acc - p * p.ln());
+        Ok(entropy.exp())
+    }
+
+    pub fn determinant(&self) -> Result<T, MathError> {
+        let (_, u, _, parity) = self.lu_parity()?;
+        let sign = T::from(parity).unwrap();
+        Ok((0..self.rows).fold(sign, |acc, i| acc * u.data[i][i]))
+    }
+
+    /// Determinant of the matrix with row `i` and column `j` removed
+    pub fn minor(&self, i: usize, j: usize) -> Result<T, MathError> {
+        if self.rows != self.cols {
+            return Err(MathError::NotSquare);
+        }
+        if self.rows < 2 {
+            return Err(MathError::InvalidInput("Matrix must be at least 2x2"));
+        }
+        if i >= self.rows || j >= self.cols {
+            return Err(MathError::IndexOutOfBounds("Index out of bounds"));
+        }
+        let data = self
+            .data
+            .iter()
+            .enumerate()
+            .filter(|&(r, _)| r != i)
+            .map(|(_, row)| {
+                row.iter()
+                    .enumerate()
+                    .filter(|&(c, _)| c != j)
+                    .map(|(_, &x)| x)
+                    .collect()
+            })
+            .collect();
+        Matrix::new(data)?.determinant()
+    }
+
+    pub fn cofactor(&self, i: usize, j: usize) -> Result<T, MathError> {
+        let minor = self.minor(i, j)?;
+        Ok(if (i + j).is_multiple_of(2) {
+            minor
+        } else {
+            -minor
+        })
+    }
+
+    /// Transpose of the cofactor matrix, so `A·adj(A) = det(A)·I`
+    pub fn adjugate(&self) -> Result<Matrix<T>, MathError> {
+        if self.rows != self.cols {
+            return Err(MathError::NotSquare);
+        }
+        if self.rows == 1 {
+            return Ok(Matrix::identity(1));
+        }
+        let mut adj = Matrix::zeros(self.rows, self.cols);
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                adj.data[j][i] = self.cofactor(i, j)?;
+            }
+        }
+        Ok(adj)
+    }
+
+    /// `adj(A) / det(A)`; only sensible for small matrices
+    pub fn inverse_adjugate(&self) -> Result<Matrix<T>, MathError> {
+        let det = self.determinant()?;
+        if det.abs() < T::from(1e-12).unwrap() {
+            return Err(MathError::Singular);
+        }
+        Ok(self.adjugate()?.scale(T::one() / det))
+    }
+
+    pub fn frobenius_norm(&self) -> T {
+        self.data
+            .iter()
+            .flatten()
+            .fold(T::zero(), |acc, &x| acc + x * x)
+            .sqrt()
+    }
+
+    /// Maximum absolute column sum
+    pub fn one_norm(&self) -> T {
+        (0..self.cols)
+            .map(|j| {
+                self.data
+                    .iter()
+                    .fold(T::zero(), |acc, row| acc + row[j].abs())
+            })
+            .fold(T::zero(), T::max)
+    }
+
+    /// Maximum absolute row sum
+    pub fn inf_norm(&self) -> T {
+        self.data
+            .iter()
+            .map(|row| row.iter().fold(T::zero(), |acc, x| acc + x.abs()))
+            .fold(T::zero(), T::max)
+    }
+
+    /// Restarted GMRES(`restart`) for square, possibly nonsymmetric systems.
+    /// Converges once `‖b - A·x‖ / ‖b‖ < tol`; `max_iter` bounds the total
+    /// number of Arnoldi steps across all restarts
+    pub fn solve_gmres(
+        &self,
+        b: &[T],
+        restart: usize,
+        tol: T,
+        max_iter: usize,
+    ) -> Result<Vec<T>, MathError> {
+        self.gmres(b, restart, tol, max_iter).map(|(x, _)| x)
+    }
+
+    /// GMRES core; also returns the number of Arnoldi steps taken
+    fn gmres(
+        &self,
+        b: &[T],
+        restart: usize,
+        tol: T,
+        max_iter: usize,
+    ) -> Result<(Vec<T>, usize), MathError> {
+        if self.rows != self.cols {
+            return Err(MathError::NotSquare);
+        }
+        if b.len() != self.rows {
+            return Err(MathError::ShapeMismatch("Right-hand side length mismatch"));
+        }
+        if restart == 0 {
+            return Err(MathError::InvalidInput("Restart length must be positive"));
+        }
+        let n = self.rows;
+        let b_norm = Self::dot(b, b).sqrt();
+        let mut x = vec![T::zero(); n];
+        if b_norm == T::zero() {
+            return Ok((x, 0));
+        }
+
+        let m = restart.min(n);
+        let mut iters = 0;
+        while iters < max_iter {
+            let ax = self.mul_vec(&x)?;
+            let mut r: Vec<T> = b.iter().zip(&ax).map(|(&bi, &ai)| bi - ai).collect();
+            let beta = Self::normalize(&mut r);
+            if beta / b_norm < tol {
+                return Ok((x, iters));
+            }
+
+            let mut basis = vec![r];
+            let mut h = vec![vec![T::zero(); m]; m + 1];
+            let (mut cs, mut sn) = (vec![T::zero(); m], vec![T::zero(); m]);
+            let mut g = vec![T::zero(); m + 1];
+            g[0] = beta;
+
+            let mut k = 0;
+            while k < m && iters < max_iter {
+                let (w, coeffs) = self.arnoldi_step(&basis)?;
+                for (i, c) in coeffs.into_iter().enumerate() {
+                    h[i][k] = c;
+                }
+                let breakdown = h[k + 1][k] < T::from(1e-14).unwrap();
+
+                // Givens rotations keep H upper triangular
+                for i in 0..k {
+                    let t = cs[i] * h[i][k] + sn[i] * h[i + 1][k];
+                    h[i + 1][k] = -sn[i] * h[i][k] + cs[i] * h[i + 1][k];
+                    h[i][k] = t;
+                }
+                let rho = h[k][k].hypot(h[k + 1][k]);
+                if rho == T::zero() {
+                    return Err(MathError::Singular);
+                }
+                cs[k] = h[k][k] / rho;
+                sn[k] = h[k + 1][k] / rho;
+                h[k][k] = rho;
+                h[k + 1][k] = T::zero();
+                g[k + 1] = -sn[k] * g[k];
+                g[k] = g[k] * cs[k];
+
+                k += 1;
+                iters += 1;
+                if breakdown || g[k].abs() / b_norm < tol {
+                    break;
+                }
+                basis.push(w);
+            }
+
+            // Back substitution on the k×k triangular system, then x += V·y
+            let mut y = vec![T::zero(); k];
+            for i in (0..k).rev() {
+                let s = (i + 1..k).fold(T::zero(), |acc, j| acc + h[i][j] * y[j]);
+                y[i] = (g[i] - s) / h[i][i];
+            }
+            for (yi, v) in y.iter().zip(&basis) {
+                x.iter_mut()
+                    .zip(v)
+                    .for_each(|(xj, &vj)| *xj = *xj + *yi * vj);
+            }
+        }
+
+        let ax = self.mul_vec(&x)?;
+        let residual = b
+            .iter()
+            .zip(&ax)
+            .fold(T::zero(), |acc, (&bi, &ai)| acc + (bi - ai).powi(2));
+        if residual.sqrt() / b_norm < tol {
+            return Ok((x, iters));
+        }
+        Err(MathError::NotConverged("GMRES did not converge"))
+    }
+
+    /// Orthogonal polar factor `U·Vᵀ`, the closest orthogonal matrix in Frobenius norm
+    pub fn nearest_orthogonal(&self, tol: T, max_iter: usize) -> Result<Matrix<T>, MathError> {
+        if self.rows != self.cols {
+            return Err(MathError::NotSquare);
+        }
+        let (u, s, vt) = self.svd(tol, max_iter)?;
+        if s.len() < self.rows {
+            return Err(MathError::InvalidInput("Matrix must be full rank"));
+        }
+        u.mul(&vt)
+    }
+
+    /// Repeatedly squares the matrix (`P, P², P⁴, ...`) until successive powers
+    /// differ by less than `tol` in Frobenius norm; returns the limit and the
+    /// number of squarings performed
+    pub fn power_until_stable(
+        &self,
+        tol: T,
+        max_iter: usize,
+    ) -> Result<(Matrix<T>, usize), MathError> {
+        if self.rows != self.cols {
+            return Err(MathError::NotSquare);
+        }
+        let mut current = self.clone();
+        for iter in 1..=max_iter {
+            let next = current.mul(&current)?;
+            let change = next.sub(&current)?.frobenius_norm();
+            current = next;
+            if change < tol {
+                return Ok((current, iter));
+            }
+        }
+        Err(MathError::NotConverged("Matrix powers did not stabilize"))
+    }
+
+    /// Largest-magnitude eigenvalue and its unit eigenvector by power
+    /// iteration. Converges when successive Rayleigh quotients differ by less
+    /// than `tol` (relative to the eigenvalue once it exceeds 1)
+    pub fn dominant_eigenpair(&self, tol: T, max_iter: usize) -> Result<(T, Vec<T>), MathError> {
+        if self.rows != self.cols {
+            return Err(MathError::NotSquare);
+        }
+        let n = self.rows;
+        let mut rng = SplitMix64::new(0x5EED);
+        let mut v: Vec<T> = (0..n)
+            .map(|_| T::from(rng.next_f64() - 0.5).unwrap())
+            .collect();
+        Self::normalize(&mut v);
+        let mut lambda = Self::rayleigh_quotient(&self.data, &v);
+        for _ in 0..max_iter {
+            let mut w = Self::mat_vec_mul(&self.data, &v);
+            if Self::normalize(&mut w) == T::zero() {
+                // A·v = 0, so v is an eigenvector of the zero eigenvalue
+                return Ok((T::zero(), v));
+            }
+            let lambda_next = Self::rayleigh_quotient(&self.data, &w);
+            v = w;
+            if (lambda_next - lambda).abs() < tol * lambda_next.abs().max(T::one()) {
+                return Ok((lambda_next, v));
+            }
+            lambda = lambda_next;
+        }
+        Err(MathError::NotConverged("Power iteration did not converge"))
+    }
+
+    /// Eigenpair whose eigenvalue is closest to `sigma`, via power iteration on
+    /// `(A - σI)⁻¹`. The shifted matrix is factored once and reused every step.
+    /// `tol` has the same relative meaning as in `dominant_eigenpair`
+    pub fn eig_shift_invert(
+        &self,
+        sigma: T,
+        tol: T,
+        max_iter: usize,
+    ) -> Result<(T, Vec<T>), MathError> {
+        if self.rows != self.cols {
+            return Err(MathError::NotSquare);
+        }
+        let n = self.rows;
+        let shifted = self.sub(&Matrix::identity(n).scale(sigma))?;
+        let (l, u, perm) = shifted.lu()?;
+        if (0..n).any(|i| u.data[i][i].abs() < T::from(1e-12).unwrap()) {
+            return Err(MathError::Singular);
+        }
+
+        let mut rng = SplitMix64::new(0x5EED);
+        let mut v: Vec<T> = (0..n)
+            .map(|_| T::from(rng.next_f64() - 0.5).unwrap())
+            .collect();
+        Self::normalize(&mut v);
+        let mut lambda = Self::rayleigh_quotient(&self.data, &v);
+        for _ in 0..max_iter {
+            let mut w = Self::lu_solve(&l, &u, &perm, &v);
+            if Self::normalize(&mut w) == T::zero() {
+                return Err(MathError::NotConverged("Shift-invert iteration broke down"));
+            }
+            let lambda_next = Self::rayleigh_quotient(&self.data, &w);
+            v = w;
+            if (lambda_next - lambda).abs() < tol * lambda_next.abs().max(T::one()) {
+                return Ok((lambda_next, v));
+            }
+            lambda = lambda_next;
+        }
+        Err(MathError::NotConverged(
+            "Shift-invert iteration did not converge",
+        ))
+    }
+
+    /// Skew-symmetric `[v]×` with `skew(a)·b = a × b` for 3-vectors
+    pub fn skew(v: &[T]) -> Result<Matrix<T>, MathError> {
+        if v.len() != 3 {
+            return Err(MathError::ShapeMismatch(
+                "Skew matrix requires a 3-dimensional vector",
+            ));
+        }
+        let z = T::zero();
+        Matrix::new(vec![
+            vec![z, -v[2], v[1]],
+            vec![v[2], z, -v[0]],
+            vec![-v[1], v[0], z],
+        ])
+    }
+
+    /// Rank-1 orthogonal projector `v·vᵀ / (vᵀ·v)` onto the line spanned by `v`
+    pub fn projection_onto(v: &Vector<T>) -> Result<Matrix<T>, MathError> {
+        let vv = Self::dot(&v.0, &v.0);
+        if vv == T::zero() {
+            return Err(MathError::DivisionByZero);
+        }
+        let n = v.0.len();
+        let mut p = Matrix::zeros(n, n);
+        for i in 0..n {
+            for j in 0..n {
+                p.data[i][j] = v.0[i] * v.0[j] / vv;
+            }
+        }
+        Ok(p)
+    }
+
+    /// Assembles a matrix from a 2D grid of blocks. Blocks in the same block
+    /// row must share a height and blocks in the same block column a width
+    pub fn block(blocks: &[Vec<Matrix<T>>]) -> Result<Matrix<T>, MathError> {
+        if blocks.is_empty() || blocks[0].is_empty() {
+            return Err(MathError::Empty("Matrix cannot be empty"));
+        }
+        let grid_cols = blocks[0].len();
+        if blocks.iter().any(|row| row.len() != grid_cols) {
+            return Err(MathError::ShapeMismatch(
+                "Every block row must have the same number of blocks",
+            ));
+        }
+        let widths: Vec<usize> = blocks[0].iter().map(|b| b.cols).collect();
+        let mut data = Vec::new();
+        for block_row in blocks {
+            let height = block_row[0].rows;
+            if block_row.iter().any(|b| b.rows != height) {
+                return Err(MathError::ShapeMismatch(
+                    "Blocks in a block row must have the same height",
+                ));
+            }
+            if block_row.iter().zip(&widths).any(|(b, &w)| b.cols != w) {
+                return Err(MathError::ShapeMismatch(
+                    "Blocks in a block column must have the same width",
+                ));
+            }
+            for r in 0..height {
+                data.push(
+                    block_row
+                        .iter()
+                        .flat_map(|b| b.data[r].iter().copied())
+                        .collect(),
+                );
+            }
+        }
+        Matrix::new(data)
+    }
+
+    /// `(A + U·C·V)⁻¹` from `A⁻¹` and `C⁻¹` via the Woodbury identity, which only
+    /// inverts a k×k matrix for an n×k `U`, k×k `C` and k×n `V`
+    pub fn woodbury_inverse_update(
+        a_inv: &Matrix<T>,
+        u: &Matrix<T>,
+        c_inv: &Matrix<T>,
+        v: &Matrix<T>,
+    ) -> Result<Matrix<T>, MathError> {
+        let n = a_inv.rows;
+        let k = c_inv.rows;
+        if a_inv.cols != n || c_inv.cols != k {
+            return Err(MathError::NotSquare);
+        }
+        if u.rows != n || u.cols != k || v.rows != k || v.cols != n {
+            return Err(MathError::ShapeMismatch(
+                "Incompatible shapes for Woodbury update",
+            ));
+        }
+        let a_inv_u = a_inv.mul(u)?;
+        let v_a_inv = v.mul(a_inv)?;
+        let capacitance = c_inv.add(&v.mul(&a_inv_u)?)?;
+        let correction = a_inv_u.mul(&capacitance.inverse()?)?.mul(&v_a_inv)?;
+        a_inv.sub(&correction)
+    }
+
+    /// Orthogonally similar upper-Hessenberg form `H = Qᵀ·A·Q` (zeros below the
+    /// first subdiagonal), built from Householder reflections
+    pub fn to_hessenberg(&self) -> Result<Matrix<T>, MathError> {
+        if self.rows != self.cols {
+            return Err(MathError::NotSquare);
+        }
+        let n = self.rows;
+        let two = T::from(2.0).unwrap();
+        let mut h = self.clone();
+        for k in 0..n.saturating_sub(2) {
+            let mut v: Vec<T> = (k + 1..n).map(|i| h.data[i][k]).collect();
+            let alpha = Self::dot(&v, &v).sqrt();
+            if alpha < T::from(1e-15).unwrap() {
+                continue;
+            }
+            v[0] = v[0] + if v[0] >= T::zero() { alpha } else { -alpha };
+            if Self::normalize(&mut v) == T::zero() {
+                continue;
+            }
+
+            // H <- P·H, touching rows k+1..n
+            for j in 0..n {
+                let dot = (k + 1..n).fold(T::zero(), |acc, i| acc + v[i - k - 1] * h.data[i][j]);
+                for i in k + 1..n {
+                    h.data[i][j] = h.data[i][j] - two * v[i - k - 1] * dot;
+                }
+            }
+            // H <- H·P, touching columns k+1..n
+            for row in h.data.iter_mut() {
+                let dot = (k + 1..n).fold(T::zero(), |acc, j| acc + row[j] * v[j - k - 1]);
+                for j in k + 1..n {
+                    row[j] = row[j] - two * dot * v[j - k - 1];
+                }
+            }
+            for i in k + 2..n {
+                h.data[i][k] = T::zero();
+            }
+        }
+        Ok(h)
+    }
+
+    /// All eigenvalues of a square matrix by Wilkinson-shifted QR iteration on
+    /// the Hessenberg form, deflating once a subdiagonal entry drops below
+    /// `tol · ‖A‖_F`. Returned in descending order. Complex eigenvalues are not
+    /// supported yet: a 2×2 block with a complex pair is approximated by its
+    /// diagonal entries (the pair's real part appears as their mean)
+    pub fn eigenvalues(&self, tol: T, max_iter: usize) -> Result<Vec<T>, MathError> {
+        let mut a = self.to_hessenberg()?;
+        let threshold = tol * self.frobenius_norm();
+        let half = T::from(0.5).unwrap();
+        // Eigenvalues of [[p, q], [r, s]], or None when they are complex
+        let block_eigs = |p: T, q: T, r: T, s: T| {
+            let mean = (p + s) * half;
+            let disc = ((p - s) * half).powi(2) + q * r;
+            (disc >= T::zero()).then(|| (mean + disc.sqrt(), mean - disc.sqrt()))
+        };
+
+        let mut eigs = Vec::with_capacity(self.rows);
+        let mut m = self.rows;
+        let mut iters = 0;
+        while m > 0 {
+            if m == 1 || a.data[m - 1][m - 2].abs() <= threshold {
+                eigs.push(a.data[m - 1][m - 1]);
+                m -= 1;
+                continue;
+            }
+            let (p, q) = (a.data[m - 2][m - 2], a.data[m - 2][m - 1]);
+            let (r, s) = (a.data[m - 1][m - 2], a.data[m - 1][m - 1]);
+            if m == 2 || a.data[m - 2][m - 3].abs() <= threshold {
+                match block_eigs(p, q, r, s) {
+                    Some((l1, l2)) => eigs.extend([l1, l2]),
+                    None => eigs.extend([p, s]),
+                }
+                m -= 2;
+                continue;
+            }
+            if iters == max_iter {
+                return Err(MathError::NotConverged("QR algorithm did not converge"));
+            }
+            iters += 1;
+
+            // Wilkinson shift: the eigenvalue of the trailing 2×2 block closest
+            // to its last diagonal entry
+            let mu = match block_eigs(p, q, r, s) {
+                Some((l1, l2)) if (l1 - s).abs() < (l2 - s).abs() => l1,
+                Some((_, l2)) => l2,
+                None => s,
+            };
+            let shifted = Matrix::from_fn(m, m, |i, j| {
+                a.data[i][j] - if i == j { mu } else { T::zero() }
+            });
+            let (q_mat, r_mat) = shifted.qr()?;
+            let next = r_mat.mul(&q_mat)?;
+            for i in 0..m {
+                for j in 0..m {
+                    a.data[i][j] = next.data[i][j] + if i == j { mu } else { T::zero() };
+                }
+            }
+        }
+        eigs.sort_by(|x, y| y.partial_cmp(x).unwrap_or(Ordering::Equal));
+        Ok(eigs)
+    }
+
+    /// Eigenvalues (descending) and orthogonal eigenvector matrix `Q` of a
+    /// symmetric matrix, with `A = Q·diag(λ)·Qᵀ`, by cyclic Jacobi rotations.
+    /// `max_iter` bounds the number of sweeps; converges once the off-diagonal
+    /// Frobenius norm drops below `tol · ‖A‖_F`. Symmetry is checked separately
+    /// to within `√ε · ‖A‖_F`, so round-off from forming the input is accepted
+    pub fn jacobi_eigen(&self, tol: T, max_iter: usize) -> Result<(Vec<T>, Matrix<T>), MathError> {
+        if self.rows != self.cols {
+            return Err(MathError::NotSquare);
+        }
+        if !self.is_symmetric(T::epsilon().sqrt() * self.frobenius_norm()) {
+            return Err(MathError::InvalidInput("Matrix must be symmetric"));
+        }
+        let n = self.rows;
+        let threshold = tol * self.frobenius_norm();
+        let mut a = self.clone();
+        let mut q = Matrix::identity(n);
+        let off_norm = |a: &Matrix<T>| {
+            let mut s = T::zero();
+            for i in 0..n {
+                for j in 0..n {
+                    if i != j {
+                        s = s + a.data[i][j] * a.data[i][j];
+                    }
+                }
+            }
+            s.sqrt()
+        };
+
+        let mut sweeps = 0;
+        while off_norm(&a) > threshold {
+            if sweeps == max_iter {
+                return Err(MathError::NotConverged("Jacobi iteration did not converge"));
+            }
+            sweeps += 1;
+            for p in 0..n {
+                for r in p + 1..n {
+                    if a.data[p][r] == T::zero() {
+                        continue;
+                    }
+                    // Rotation angle that zeroes a[p][r], taking the smaller root
+                    let theta =
+                        (a.data[r][r] - a.data[p][p]) / (T::from(2.0).unwrap() * a.data[p][r]);
+                    let t = theta.signum() / (theta.abs() + (theta * theta + T::one()).sqrt());
+                    let c = T::one() / (t * t + T::one()).sqrt();
+                    let s = t * c;
+                    for k in 0..n {
+                        let (akp, akr) = (a.data[k][p], a.data[k][r]);
+                        a.data[k][p] = c * akp - s * akr;
+                        a.data[k][r] = s * akp + c * akr;
+                        let (qkp, qkr) = (q.data[k][p], q.data[k][r]);
+                        q.data[k][p] = c * qkp - s * qkr;
+                        q.data[k][r] = s * qkp + c * qkr;
+                    }
+                    for k in 0..n {
+                        let (apk, ark) = (a.data[p][k], a.data[r][k]);
+                        a.data[p][k] = c * apk - s * ark;
+                        a.data[r][k] = s * apk + c * ark;
+                    }
+                }
+            }
+        }
+
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&i, &j| {
+            a.data[j][j]
+                .partial_cmp(&a.data[i][i])
+                .unwrap_or(Ordering::Equal)
+        });
+        let eigs = order.iter().map(|&i| a.data[i][i]).collect();
+        let vectors = Matrix::from_fn(n, n, |i, j| q.data[i][order[j]]);
+        Ok((eigs, vectors))
+    }
+
+    /// Moore-Penrose pseudoinverse `V·Σ⁺·Uᵀ`; singular values below `tol` are
+    /// treated as zero
+    pub fn pinv(&self, tol: T, max_iter: usize) -> Result<Matrix<T>, MathError> {
+        let (u, s, vt) = self.svd(tol, max_iter)?;
+        let mut out = Matrix::zeros(self.cols, self.rows);
+        for (k, &sigma) in s.iter().enumerate() {
+            if sigma < tol {
+                continue;
+            }
+            for i in 0..self.cols {
+                for j in 0..self.rows {
+                    out.data[i][j] = out.data[i][j] + vt.data[k][i] * u.data[j][k] / sigma;
+                }
+            }
+        }
+        Ok(out)
+    }
+
+    fn column_means(&self) -> Vec<T> {
+        let n = T::from(self.rows).unwrap();
+        (0..self.cols)
+            .map(|j| self.data.iter().fold(T::zero(), |acc, row| acc + row[j]) / n)
+            .collect()
+    }
+
+    /// Subtracts each column's mean so every column averages to zero
+    pub fn center_columns(&self) -> Matrix<T> {
+        let means = self.column_means();
+        Matrix::from_fn(self.rows, self.cols, |i, j| self.data[i][j] - means[j])
+    }
+
+    /// Column-wise z-scores using the population standard deviation, matching
+    /// `Vector::standardize`
+    pub fn standardize_columns(&self) -> Result<Matrix<T>, MathError> {
+        let centered = self.center_columns();
+        let n = T::from(self.rows).unwrap();
+        let std: Vec<T> = (0..self.cols)
+            .map(|j| {
+                (centered
+                    .data
+                    .iter()
+                    .fold(T::zero(), |acc, row| acc + row[j] * row[j])
+                    / n)
+                    .sqrt()
+            })
+            .collect();
+        if std.contains(&T::zero()) {
+            return Err(MathError::InvalidInput(
+                "Columns must have non-zero variance",
+            ));
+        }
+        Ok(Matrix::from_fn(self.rows, self.cols, |i, j| {
+            centered.data[i][j] / std[j]
+        }))
+    }
+
+    /// Principal component analysis with rows as observations. Returns the top
+    /// `n_components` directions as the rows of an `n_components×cols` matrix,
+    /// ordered by decreasing explained variance `σ²/(n-1)`
+    pub fn pca(
+        &self,
+        n_components: usize,
+        tol: T,
+        max_iter: usize,
+    ) -> Result<(Matrix<T>, Vec<T>), MathError> {
+        if n_components > self.cols {
+            return Err(MathError::InvalidInput(
+                "Number of components exceeds number of columns",
+            ));
+        }
+        if self.rows < 2 {
+            return Err(MathError::Empty("PCA requires at least two observations"));
+        }
+        let (_, s, vt) = self.center_columns().svd_full(tol, max_iter)?;
+        let denom = T::from(self.rows - 1).unwrap();
+        let variance = (0..n_components)
+            .map(|k| s.get(k).map_or(T::zero(), |&sigma| sigma * sigma / denom))
+            .collect();
+        let components = Matrix::from_fn(n_components, self.cols, |i, j| vt.data[i][j]);
+        Ok((components, variance))
+    }
+
+    /// cols×cols covariance treating rows as observations and columns as
+    /// variables, normalized by `1 / (rows - ddof)`
+    pub fn covariance(&self, ddof: usize) -> Result<Matrix<T>, MathError> {
+        if self.rows <= ddof {
+            return Err(MathError::InvalidInput(
+                "Number of observations must exceed ddof",
+            ));
+        }
+        let centered = self.center_columns();
+        let cov = centered.contract(&centered, Axis::Rows, Axis::Rows)?;
+        Ok(cov.scale(T::one() / T::from(self.rows - ddof).unwrap()))
+    }
+
+    /// Pearson correlation between columns: the covariance scaled by the outer
+    /// product of column standard deviations, so the diagonal is all ones
+    pub fn correlation(&self) -> Result<Matrix<T>, MathError> {
+        let cov = self.covariance(0)?;
+        let std: Vec<T> = cov.diagonal().iter().map(|v| v.sqrt()).collect();
+        if std.contains(&T::zero()) {
+            return Err(MathError::InvalidInput(
+                "Columns must have non-zero variance",
+            ));
+        }
+        Ok(Matrix::from_fn(self.cols, self.cols, |i, j| {
+            if i == j {
+                T::one()
+            } else {
+                cov.data[i][j] / (std[i] * std[j])
+            }
+        }))
+    }
+
+    /// `σ_max / σ_min` over the nonzero singular values, so a rank-deficient
+    /// matrix is measured on its range; infinite only for the zero matrix
+    pub fn condition_number(&self, tol: T, max_iter: usize) -> Result<T, MathError> {
+        let (_, s, _) = self.svd(tol, max_iter)?;
+        let nonzero = s.iter().cloned().filter(|&x| x > T::zero());
+        let max = nonzero.clone().fold(T::zero(), T::max);
+        let min = nonzero.fold(T::infinity(), T::min);
+        if max == T::zero() {
+            return Ok(T::infinity());
+        }
+        Ok(max / min)
+    }
+
+    /// Number of singular values above `tol · σ_max`
+    pub fn rank(&self, tol: T, max_iter: usize) -> Result<usize, MathError> {
+        let (_, s, _) = self.svd(tol, max_iter)?;
+        let max = s.iter().cloned().fold(T::zero(), T::max);
+        Ok(s.iter().filter(|&&x| x > tol * max).count())
+    }
+
+    /// Reduced row echelon form by Gauss-Jordan elimination with partial
+    /// pivoting; candidate pivots with magnitude at most `tol` count as zero
+    pub fn rref(&self, tol: T) -> Matrix<T> {
+        self.rref_with_rank(tol).0
+    }
+
+    /// Number of pivots in the reduced row echelon form; cheaper than the
+    /// SVD-based `rank` but more sensitive to the choice of `tol`
+    pub fn rank_rref(&self, tol: T) -> usize {
+        self.rref_with_rank(tol).1
+    }
+
+    fn rref_with_rank(&self, tol: T) -> (Matrix<T>, usize) {
+        let mut m = self.clone();
+        let mut pivot_row = 0;
+        for c in 0..self.cols {
+            if pivot_row == self.rows {
+                break;
+            }
+            let p = (pivot_row..self.rows)
+                .max_by(|&i, &j| Self::cmp_abs(m.data[i][c], m.data[j][c]))
+                .unwrap();
+            if m.data[p][c].abs() <= tol {
+                for i in pivot_row..self.rows {
+                    m.data[i][c] = T::zero();
+                }
+                continue;
+            }
+            m.data.swap(p, pivot_row);
+            let pivot = m.data[pivot_row][c];
+            for x in m.data[pivot_row].iter_mut() {
+                *x = *x / pivot;
+            }
+            for i in 0..self.rows {
+                if i == pivot_row {
+                    continue;
+                }
+                let factor = m.data[i][c];
+                for j in c..self.cols {
+                    m.data[i][j] = m.data[i][j] - factor * m.data[pivot_row][j];
+                }
+            }
+            pivot_row += 1;
+        }
+        (m, pivot_row)
+    }
+
+    /// One modified Gram-Schmidt Arnoldi step: orthogonalizes `A·q_last`
+    /// against `basis`, returning the normalized new vector and the Hessenberg
+    /// column `[h_0, ..., h_k, ‖w‖]`
+    fn arnoldi_step(&self, basis: &[Vec<T>]) -> Result<(Vec<T>, Vec<T>), MathError> {
+        let mut w = self.mul_vec(basis.last().unwrap())?;
+        let mut coeffs = Vec::with_capacity(basis.len() + 1);
+        for v in basis {
+            let h = Self::dot(&w, v);
+            w.iter_mut().zip(v).for_each(|(wi, &vi)| *wi = *wi - h * vi);
+            coeffs.push(h);
+        }
+        coeffs.push(Self::normalize(&mut w));
+        Ok((w, coeffs))
+    }
+
+    /// `k` Arnoldi steps from `v0`: an n×(k+1) orthonormal Krylov basis `Q` and
+    /// a (k+1)×k upper-Hessenberg `H` with `A·Q[:, ..k] = Q·H`
+    pub fn arnoldi(&self, v0: &[T], k: usize) -> Result<(Matrix<T>, Matrix<T>), MathError> {
+        if self.rows != self.cols {
+            return Err(MathError::NotSquare);
+        }
+        if v0.len() != self.rows {
+            return Err(MathError::ShapeMismatch("Start vector length mismatch"));
+        }
+        if k == 0 {
+            return Err(MathError::InvalidInput("Number of steps must be positive"));
+        }
+        let mut q0 = v0.to_vec();
+        if Self::normalize(&mut q0) == T::zero() {
+            return Err(MathError::InvalidInput("Start vector must be non-zero"));
+        }
+
+        let mut basis = vec![q0];
+        let mut h = Matrix::zeros(k + 1, k);
+        for j in 0..k {
+            let (w, coeffs) = self.arnoldi_step(&basis)?;
+            if coeffs[j + 1] < T::from(1e-14).unwrap() {
+                return Err(MathError::NotConverged(
+                    "Arnoldi breakdown: Krylov subspace is invariant",
+                ));
+            }
+            for (i, c) in coeffs.into_iter().enumerate() {
+                h.data[i][j] = c;
+            }
+            basis.push(w);
+        }
+
+        let mut q = Matrix::zeros(self.rows, k + 1);
+        for (j, v) in basis.iter().enumerate() {
+            q.set_col(j, v)?;
+        }
+        Ok((q, h))
+    }
+
+    /// Rebuilds `U·diag(s)·Vᵀ`; truncating the factors gives a low-rank approximation
+    pub fn from_svd(u: &Matrix<T>, s: &[T], vt: &Matrix<T>) -> Result<Matrix<T>, MathError> {
+        if u.cols != s.len() || vt.rows != s.len() {
+            return Err(MathError::ShapeMismatch(
+                "SVD factor shapes are inconsistent",
+            ));
+        }
+        let mut us = u.clone();
+        for row in us.data.iter_mut() {
+            for (x, &sigma) in row.iter_mut().zip(s) {
+                *x = *x * sigma;
+            }
+        }
+        us.mul(vt)
+    }
+
+    /// Extends orthonormal `basis` vectors of length `dim` to a full orthonormal
+    /// basis with Gram-Schmidt on seeded random vectors
+    fn complete_basis(mut basis: Vec<Vec<T>>, dim: usize) -> Vec<Vec<T>> {
+        let mut rng = SplitMix64::new(0xBA515);
+        while basis.len() < dim {
+            let mut v: Vec<T> = (0..dim)
+                .map(|_| T::from(rng.next_f64() - 0.5).unwrap())
+                .collect();
+            // Two passes of modified Gram-Schmidt for numerical orthogonality
+            for _ in 0..2 {
+                for b in &basis {
+                    let d = Self::dot(&v, b);
+                    v.iter_mut().zip(b).for_each(|(x, &y)| *x = *x - d * y);
+                }
+            }
+            if Self::normalize(&mut v) > T::from(1e-8).unwrap() {
+                basis.push(v);
+            }
+        }
+        basis
+    }
+
+    /// Full SVD: square orthogonal `U` (rows×rows) and `Vᵀ` (cols×cols), with
+    /// `min(rows, cols)` singular values zero-padded past the numerical rank
+    pub fn svd_full(&self, tol: T, max_iter: usize) -> Result<(Self, Vec<T>, Self), MathError> {
+        let (u, mut s, vt) = self.svd(tol, max_iter)?;
+        let r = s.len();
+        let u_cols = Self::complete_basis(u.cols().take(r).collect(), self.rows);
+        let v_rows = Self::complete_basis(vt.data.into_iter().take(r).collect(), self.cols);
+        s.resize(self.rows.min(self.cols), T::zero());
+
+        let u_full = Matrix::from_fn(self.rows, self.rows, |i, j| u_cols[j][i]);
+        let vt_full = Matrix::new(v_rows)?;
+        Ok((u_full, s, vt_full))
+    }
+
+    /// Jacobi-preconditioned GMRES: solves `D⁻¹·A·x = D⁻¹·b` with `D = diag(A)`,
+    /// which evens out badly scaled rows. `tol` applies to the preconditioned
+    /// residual
+    pub fn solve_preconditioned(
+        &self,
+        b: &[T],
+        tol: T,
+        max_iter: usize,
+    ) -> Result<Vec<T>, MathError> {
+        self.jacobi_gmres(b, tol, max_iter).map(|(x, _)| x)
+    }
+
+    fn jacobi_gmres(&self, b: &[T], tol: T, max_iter: usize) -> Result<(Vec<T>, usize), MathError> {
+        if self.rows != self.cols {
+            return Err(MathError::NotSquare);
+        }
+        if b.len() != self.rows {
+            return Err(MathError::ShapeMismatch("Right-hand side length mismatch"));
+        }
+        let diag = self.diagonal();
+        if diag.contains(&T::zero()) {
+            return Err(MathError::InvalidInput(
+                "Jacobi preconditioner requires a non-zero diagonal",
+            ));
+        }
+        let scaled = Matrix::from_fn(self.rows, self.cols, |i, j| self.data[i][j] / diag[i]);
+        let rhs: Vec<T> = b.iter().zip(&diag).map(|(&bi, &d)| bi / d).collect();
+        scaled.gmres(&rhs, self.rows, tol, max_iter)
+    }
+}
+
+impl<T: Float + fmt::Display> Matrix<T> {
+    /// Renders one bracketed row per line with `precision` decimals. Each column
+    /// is right-aligned to at least `width` characters, widening to fit its
+    /// longest entry so large values never break the alignment
+    pub fn format(&self, precision: usize, width: usize) -> String {
+        let cells: Vec<Vec<String>> = self
+            .data
+            .iter()
+            .map(|row| row.iter().map(|x| format!("{:.*}", precision, x)).collect())
+            .collect();
+        Self::align_cells(&cells, width)
+    }
+
+    /// LaTeX `bmatrix` with `precision` decimals, one row per line
+    pub fn to_latex(&self, precision: usize) -> String {
+        let rows: Vec<String> = self
+            .data
+            .iter()
+            .map(|row| {
+                let cells: Vec<String> =
+                    row.iter().map(|x| format!("{:.*}", precision, x)).collect();
+                format!("  {}", cells.join(" & "))
+            })
+            .collect();
+        format!(
+            "\\begin{{bmatrix}}\n{}\n\\end{{bmatrix}}",
+            rows.join(" \\\\\n")
+        )
+    }
+
+    /// Markdown pipe table with `precision` decimals. Tables need a header, so
+    /// columns are labelled from 1 (`C1`, `C2`, ...)
+    pub fn to_markdown(&self, precision: usize) -> String {
+        let line = |cells: Vec<String>| format!("| {} |\n", cells.join(" | "));
+        let mut out = line((1..=self.cols).map(|j| format!("C{}", j)).collect());
+        out.push_str(&line(vec!["---".to_string(); self.cols]));
+        for row in &self.data {
+            out.push_str(&line(
+                row.iter().map(|x| format!("{:.*}", precision, x)).collect(),
+            ));
+        }
+        out
+    }
+
+    /// Snapshots of Gaussian elimination to row echelon form, one per step,
+    /// starting with the input. Rows are labelled from 1 (`R1`, `R2`, ...)
+    pub fn elimination_trace(&self) -> Vec<(String, Matrix<T>)> {
+        let mut m = self.clone();
+        let mut steps = vec![("Initial matrix".to_string(), m.clone())];
+        let mut pivot_row = 0;
+        for c in 0..self.cols {
+            if pivot_row == self.rows {
+                break;
+            }
+            let p = (pivot_row..self.rows)
+                .max_by(|&i, &j| Self::cmp_abs(m.data[i][c], m.data[j][c]))
+                .unwrap();
+            if m.data[p][c].abs() < T::from(1e-12).unwrap() {
+                continue;
+            }
+            if p != pivot_row {
+                m.data.swap(p, pivot_row);
+                steps.push((format!("Swap R{} and R{}", pivot_row + 1, p + 1), m.clone()));
+            }
+            steps.push((
+                format!(
+                    "Pivot {} at R{} C{}",
+                    m.data[pivot_row][c],
+                    pivot_row + 1,
+                    c + 1
+                ),
+                m.clone(),
+            ));
+            for i in pivot_row + 1..self.rows {
+                let factor = m.data[i][c] / m.data[pivot_row][c];
+                if factor == T::zero() {
+                    continue;
+                }
+                for j in c..self.cols {
+                    m.data[i][j] = m.data[i][j] - factor * m.data[pivot_row][j];
+                }
+                m.data[i][c] = T::zero();
+                steps.push((
+                    format!(
+                        "R{} <- R{} - ({}) * R{}",
+                        i + 1,
+                        i + 1,
+                        factor,
+                        pivot_row + 1
+                    ),
+                    m.clone(),
+                ));
+            }
+            pivot_row += 1;
+        }
+        steps
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl<T: Float + Send + Sync> Matrix<T> {
+    /// Same product as `mul`, with output rows computed in parallel on the
+    /// rayon thread pool. Requires the `parallel` feature
+    pub fn par_mul(&self, other: &Matrix<T>) -> Result<Matrix<T>, MathError> {
+        use rayon::prelude::*;
+
+        if self.cols != other.rows {
+            return Err(MathError::ShapeMismatch(
+                "Incompatible shapes for multiplication",
+            ));
+        }
+        let data = self
+            .data
+            .par_iter()
+            .map(|row| {
+                let mut out_row = vec![T::zero(); other.cols];
+                for (&a, other_row) in row.iter().zip(&other.data) {
+                    for (o, &b) in out_row.iter_mut().zip(other_row) {
+                        *o = *o + a * b;
+                    }
+                }
+                out_row
+            })
+            .collect();
+        Ok(Matrix {
+            data,
+            rows: self.rows,
+            cols: other.cols,
+        })
+    }
+}
+
+/// m×n Jacobian of `f: R^n -> R^m` at `x` using central differences
+pub fn numerical_jacobian<T: Float, F>(f: F, x: &[T], h: T) -> Result<Matrix<T>, MathError>
+where
+    F: Fn(&[T]) -> Vec<T>,
+{
+    if h <= T::zero() || !h.is_finite() {
+        return Err(MathError::InvalidInput("Step size must be positive"));
+    }
+    central_jacobian(&f, x, |_| h)
+}
+
+/// Central-difference Jacobian with step `step(j)` along coordinate `j`
+fn central_jacobian<T: Float, F>(
+    f: &F,
+    x: &[T],
+    step: impl Fn(usize) -> T,
+) -> Result<Matrix<T>, MathError>
+where
+    F: Fn(&[T]) -> Vec<T>,
+{
+    if x.is_empty() {
+        return Err(MathError::Empty("Point must have at least one element"));
+    }
+
+    let m = f(x).len();
+    if m == 0 {
+        return Err(MathError::Empty(
+            "Function must return at least one element",
+        ));
+    }
+
+    let mut jac = Matrix::zeros(m, x.len());
+    let mut xp = x.to_vec();
+    let mut xm = x.to_vec();
+    for j in 0..x.len() {
+        let h = step(j);
+        xp[j] = x[j] + h;
+        xm[j] = x[j] - h;
+        let fp = f(&xp);
+        let fm = f(&xm);
+        if fp.len() != m || fm.len() != m {
+            return Err(MathError::ShapeMismatch(
+                "Function output length must not change",
+            ));
+        }
+        // Divide by the step actually taken, which rounding may have changed
+        let width = xp[j] - xm[j];
+        for (row, (&p, &q)) in jac.data.iter_mut().zip(fp.iter().zip(&fm)) {
+            row[j] = (p - q) / width;
+        }
+        xp[j] = x[j];
+        xm[j] = x[j];
+    }
+    Ok(jac)
+}
+
+/// Newton's method for `f(x) = 0` using a finite-difference Jacobian
+pub fn newton_solve<T: Float, F>(
+    f: F,
+    x0: &[T],
+    tol: T,
+    max_iter: usize,
+) -> Result<Vec<T>, MathError>
+where
+    F: Fn(&[T]) -> Vec<T>,
+{
+    let mut x = x0.to_vec();
+    for _ in 0..max_iter {
+        let fx = f(&x);
+        if fx.len() != x.len() {
+            return Err(MathError::ShapeMismatch(
+                "Function output length must match input length",
+            ));
+        }
+        if fx.iter().fold(T::zero(), |acc, &v| acc + v * v).sqrt() < tol {
+            return Ok(x);
+        }
+        // A fixed step vanishes below the float spacing of large iterates, so
+        // scale it to each coordinate's magnitude
+        let root_eps = T::epsilon().sqrt();
+        let jac = central_jacobian(&f, &x, |j| root_eps * x[j].abs().max(T::one()))?;
+        let step = jac.solve(&fx)?;
+        for (xi, si) in x.iter_mut().zip(&step) {
+            *xi = *xi - *si;
+        }
+    }
+    Err(MathError::NotConverged("Newton's method did not converge"))
+}
+
+impl<T: Float> ops::Add for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn add(self, rhs: &Matrix<T>) -> Matrix<T> {
+        Matrix::add(self, rhs).unwrap_or_else(|e| panic!("{}", e))
+    }
+}
+
+impl<T: Float> ops::Sub for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn sub(self, rhs: &Matrix<T>) -> Matrix<T> {
+        Matrix::sub(self, rhs).unwrap_or_else(|e| panic!("{}", e))
+    }
+}
+
+impl<T: Float> ops::Mul for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: &Matrix<T>) -> Matrix<T> {
+        Matrix::mul(self, rhs).unwrap_or_else(|e| panic!("{}", e))
+    }
+}
+
+/// Parses MATLAB-style literals such as `"1 2; 3 4"`: rows separated by `;`,
+/// entries by whitespace. A bad entry reports its 1-based row as `line`. A
+/// trailing `;` is allowed, but an empty row elsewhere is a shape error
+impl<T: Float + FromStr> FromStr for Matrix<T> {
+    type Err = MathError;
+
+    fn from_str(s: &str) -> Result<Self, MathError> {
+        let rows: Vec<&str> = s.split(';').collect();
+        let len = rows
+            .iter()
+            .rposition(|row| !row.trim().is_empty())
+            .map_or(0, |k| k + 1);
+        let data = rows[..len]
+            .iter()
+            .enumerate()
+            .map(|(i, row)| {
+                row.split_whitespace()
+                    .enumerate()
+                    .map(|(j, cell)| {
+                        cell.parse().map_err(|_| MathError::Parse {
+                            line: i + 1,
+                            column: j + 1,
+                        })
+                    })
+                    .collect::<Result<Vec<T>, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Matrix::new(data)
+    }
+}
+
+impl<T> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        &self.data[row][col]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        &mut self.data[row][col]
+    }
+}
+
+/// Defaults to 4 decimals in 8-character fields; `{:.2}` and `{:12}` override
+/// the precision and minimum field width
+impl<T: Float + fmt::Display> fmt::Display for Matrix<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let precision = f.precision().unwrap_or(4);
+        let width = f.width().unwrap_or(8);
+        f.write_str(&self.format(precision, width))
+    }
+}
+
+impl<T: Float + fmt::LowerExp> Matrix<T> {
+    /// Like `format` but in scientific notation (`1.50e-9`), which keeps tiny
+    /// and huge entries readable side by side. Columns are aligned to their
+    /// longest entry
+    pub fn to_string_sci(&self, precision: usize) -> String {
+        let cells: Vec<Vec<String>> = self
+            .data
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|x| format!("{:.*e}", precision, x))
+                    .collect()
+            })
+            .collect();
+        Self::align_cells(&cells, 0)
+    }
+}
+
+/// `{:e}` renders entries in scientific notation, honoring precision and width
+/// like `Display`; without a precision each entry uses its shortest form
+impl<T: Float + fmt::LowerExp> fmt::LowerExp for Matrix<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cells: Vec<Vec<String>> = self
+            .data
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|x| match f.precision() {
+                        Some(p) => format!("{:.*e}", p, x),
+                        None => format!("{:e}", x),
+                    })
+                    .collect()
+            })
+            .collect();
+        f.write_str(&Self::align_cells(&cells, f.width().unwrap_or(0)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: &Matrix, expected: &[Vec<f64>], tol: f64) {
+        assert_eq!(actual.rows, expected.len(), "row count differs");
+        for (a_row, e_row) in actual.data.iter().zip(expected) {
+            assert_eq!(a_row.len(), e_row.len(), "column count differs");
+            for (a, e) in a_row.iter().zip(e_row) {
+                assert!(
+                    (a - e).abs() < tol,
+                    "expected {:?}, got {:?}",
+                    expected,
+                    actual.data
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn it_should_return_trace_correctly() {
+        let m = Matrix::new(vec![
+            vec![1.0, 5.0, 7.0],
+            vec![4.0, 2.0, 8.0],
+            vec![6.0, 9.0, 3.0],
+        ])
+        .unwrap();
+        assert_eq!(m.trace().unwrap(), 6.0);
+    }
+
+    #[test]
+    fn it_should_return_error_for_trace_of_non_square() {
+        let m = Matrix::new(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]).unwrap();
+        assert_eq!(m.trace().unwrap_err(), MathError::NotSquare);
+    }
+
+    #[test]
+    fn it_should_compute_numerical_jacobian_correctly() {
+        let f = |x: &[f64]| vec![x[0] * x[0], x[0] * x[1]];
+        let x = [3.0, 2.0];
+        let jac = numerical_jacobian(f, &x, 1e-5).unwrap();
+        assert_close(&jac, &[vec![6.0, 0.0], vec![2.0, 3.0]], 1e-6);
+    }
+
+    #[test]
+    fn it_should_return_error_for_non_positive_step() {
+        let f = |x: &[f64]| x.to_vec();
+        let result = numerical_jacobian(f, &[1.0], 0.0);
+        assert_eq!(
+            result.unwrap_err(),
+            MathError::InvalidInput("Step size must be positive")
+        );
+    }
+
+    #[test]
+    fn it_should_find_root_with_newton_solve() {
+        // x² + y² = 4, x = y  =>  x = y = √2
+        let f = |x: &[f64]| vec![x[0] * x[0] + x[1] * x[1] - 4.0, x[0] - x[1]];
+        let root = newton_solve(f, &[1.0, 0.5], 1e-10, 50).unwrap();
+        let expected = 2.0_f64.sqrt();
+        assert!((root[0] - expected).abs() < 1e-8);
+        assert!((root[1] - expected).abs() < 1e-8);
+    }
+
+    #[test]
+    fn it_should_scale_newton_jacobian_step_to_the_iterate() {
+        // A fixed 1e-7 step is below the float spacing at 1e10 and used to
+        // produce an all-zero Jacobian
+        let f = |x: &[f64]| vec![x[0] * x[0] - 2.0];
+        let root = newton_solve(f, &[1e10], 1e-9, 100).unwrap();
+        assert!((root[0] - 2.0_f64.sqrt()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn it_should_run_newton_solve_in_f32() {
+        let f = |x: &[f32]| vec![x[0] * x[0] - 2.0];
+        for x0 in [1.0_f32, 1000.0] {
+            let root = newton_solve(f, &[x0], 1e-5, 100).unwrap();
+            assert!((root[0] - 2.0_f32.sqrt()).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn it_should_return_error_when_newton_does_not_converge() {
+        let f = |x: &[f64]| vec![x[0] * x[0] + 1.0];
+        let result = newton_solve(f, &[1.0], 1e-10, 20);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn it_should_report_newton_errors_by_cause() {
+        // f'(0) = 0, so the first Newton step hits a singular Jacobian
+        let f = |x: &[f64]| vec![x[0] * x[0] + 1.0];
+        assert_eq!(
+            newton_solve(f, &[0.0], 1e-10, 20).unwrap_err(),
+            MathError::Singular
+        );
+        let g = |x: &[f64]| vec![x[0], x[0]];
+        assert_eq!(
+            newton_solve(g, &[1.0], 1e-10, 20).unwrap_err(),
+            MathError::ShapeMismatch("Function output length must match input length")
+        );
+    }
+
+    #[test]
+    fn it_should_compute_qr_correctly() {
+        let a = Matrix::new(vec![
+            vec![12.0, -51.0, 4.0],
+            vec![6.0, 167.0, -68.0],
+            vec![-4.0, 24.0, -41.0],
+            vec![1.0, 2.0, 3.0],
+        ])
+        .unwrap();
+        let (q, r) = a.qr().unwrap();
+
+        assert_eq!((q.rows, q.cols), (4, 4));
+        assert_eq!((r.rows, r.cols), (4, 3));
+        assert_close(
+            &q.transpose().mul(&q).unwrap(),
+            &Matrix::identity(4).data,
+            1e-10,
+        );
+        for i in 0..r.rows {
+            for j in 0..i.min(r.cols) {
+                assert_eq!(r.data[i][j], 0.0);
+            }
+        }
+        assert_close(&q.mul(&r).unwrap(), &a.data, 1e-10);
+    }
+
+    #[test]
+    fn it_should_return_error_for_qr_of_wide_matrix() {
+        let a = Matrix::new(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]).unwrap();
+        assert_eq!(
+            a.qr().unwrap_err(),
+            MathError::InvalidInput("QR requires rows >= cols")
+        );
+    }
+
+    #[test]
+    fn it_should_recover_all_singular_values_of_scaled_identity() {
+        // AᵀA = 4I: after deflating the first component, an all-ones start
+        // vector lies in the null space and svd stopped at one singular value
+        let a = Matrix::identity(3).scale(2.0);
+        let (_, s, _) = a.svd(1e-12, 1000).unwrap();
+        assert_eq!(s.len(), 3);
+        assert!(s.iter().all(|&x| (x - 2.0).abs() < 1e-9));
+    }
+
+    #[test]
+    fn it_should_converge_singular_vectors_for_close_singular_values() {
+        // Singular values 1 and 0.999: the Rayleigh quotient settles long
+        // before the power-iteration vector, which stays visibly off axis
+        let t = 0.4_f64;
+        let rot = Matrix::new(vec![vec![t.cos(), -t.sin()], vec![t.sin(), t.cos()]]).unwrap();
+        let a = Matrix::new(vec![vec![1.0, 0.0], vec![0.0, 0.999]])
+            .unwrap()
+            .mul(&rot)
+            .unwrap();
+        let (_, s, vt) = a.svd(1e-10, 100_000).unwrap();
+        assert!((s[0] - 1.0).abs() < 1e-9);
+        assert!((s[1] - 0.999).abs() < 1e-9);
+        // The leading right singular vector is the first row of the rotation
+        let align = vt.data[0][0] * t.cos() - vt.data[0][1] * t.sin();
+        assert!((align.abs() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn it_should_evaluate_quadratic_form_batch_correctly() {
+        let a = Matrix::new(vec![vec![2.0, 1.0], vec![1.0, 3.0]]).unwrap();
+        let points = vec![vec![1.0, 0.0], vec![1.0, 2.0], vec![-3.0, 0.5]];
+        let batch = a.quadratic_form_batch(&points).unwrap();
+        assert_eq!(batch.len(), points.len());
+        for (x, q) in points.iter().zip(&batch) {
+            assert_eq!(*q, a.quadratic_form(x).unwrap());
+        }
+        assert_eq!(batch[1], 18.0);
+    }
+
+    #[test]
+    fn it_should_return_error_for_quadratic_form_batch_length_mismatch() {
+        let a = Matrix::new(vec![vec![2.0, 1.0], vec![1.0, 3.0]]).unwrap();
+        let result = a.quadratic_form_batch(&[vec![1.0, 2.0], vec![1.0]]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn it_should_compute_cholesky_correctly() {
+        let a = Matrix::new(vec![
+            vec![4.0, 12.0, -16.0],
+            vec![12.0, 37.0, -43.0],
+            vec![-16.0, -43.0, 98.0],
+        ])
+        .unwrap();
+        let l = a.cholesky().unwrap();
+        assert_close(
+            &l,
+            &[
+                vec![2.0, 0.0, 0.0],
+                vec![6.0, 1.0, 0.0],
+                vec![-8.0, 5.0, 3.0],
+            ],
+            1e-12,
+        );
+        assert_close(&l.mul(&l.transpose()).unwrap(), &a.data, 1e-12);
+    }
+
+    #[test]
+    fn it_should_return_error_for_cholesky_of_non_positive_definite() {
+        let a = Matrix::new(vec![vec![1.0, 2.0], vec![2.0, 1.0]]).unwrap();
+        assert_eq!(
+            a.cholesky().unwrap_err(),
+            MathError::InvalidInput("Matrix is not positive definite")
+        );
+    }
+
+    #[test]
+    fn it_should_return_error_for_cholesky_of_non_symmetric() {
+        let a = Matrix::new(vec![vec![4.0, 1.0], vec![0.0, 3.0]]).unwrap();
+        assert_eq!(
+            a.cholesky().unwrap_err(),
+            MathError::InvalidInput("Matrix must be symmetric")
+        );
+    }
+
+    #[test]
+    fn it_should_add_and_sub_correctly() {
+        let a = Matrix::new(vec![vec![1.0, 2.0], vec![3.0, 4.0]]).unwrap();
+        let b = Matrix::new(vec![vec![5.0, 6.0], vec![7.0, 8.0]]).unwrap();
+        assert_eq!(
+            a.add(&b).unwrap().data,
+            vec![vec![6.0, 8.0], vec![10.0, 12.0]]
+        );
+        assert_eq!(
+            b.sub(&a).unwrap().data,
+            vec![vec![4.0, 4.0], vec![4.0, 4.0]]
+        );
+    }
+
+    #[test]
+    fn it_should_return_error_for_add_shape_mismatch() {
+        let a = Matrix::new(vec![vec![1.0, 2.0], vec![3.0, 4.0]]).unwrap();
+        let b = Matrix::new(vec![vec![1.0, 2.0, 3.0]]).unwrap();
+        assert_eq!(
+            a.add(&b).unwrap_err(),
+            MathError::ShapeMismatch("Matrix shapes must match")
+        );
+        assert_eq!(
+            a.sub(&b).unwrap_err(),
+            MathError::ShapeMismatch("Matrix shapes must match")
+        );
+    }
+
+    #[test]
+    fn it_should_scale_by_zero_to_zero_matrix() {
+        let a = Matrix::new(vec![vec![1.0, 2.0], vec![3.0, 4.0]]).unwrap();
+        assert_eq!(a.scale(0.0).data, Matrix::zeros(2, 2).data);
+        assert_eq!(a.scale(2.0).data, vec![vec![2.0, 4.0], vec![6.0, 8.0]]);
+    }
+
+    #[test]
+    fn it_should_detect_row_stochastic_matrix() {
+        let p = Matrix::new(vec![vec![0.9, 0.1], vec![0.5, 0.5]]).unwrap();
+        assert!(p.is_row_stochastic(1e-12));
+        let q = Matrix::new(vec![vec![0.9, 0.2], vec![0.5, 0.5]]).unwrap();
+        assert!(!q.is_row_stochastic(1e-12));
+        let r = Matrix::new(vec![vec![1.5, -0.5], vec![0.5, 0.5]]).unwrap();
+        assert!(!r.is_row_stochastic(1e-12));
+    }
+
+    #[test]
+    fn it_should_normalize_to_row_stochastic() {
+        let a = Matrix::new(vec![vec![1.0, 3.0], vec![2.0, 2.0], vec![0.0, 5.0]]).unwrap();
+        let p = a.to_row_stochastic().unwrap();
+        assert_close(
+            &p,
+            &[vec![0.25, 0.75], vec![0.5, 0.5], vec![0.0, 1.0]],
+            1e-12,
+        );
+        assert!(p.is_row_stochastic(1e-12));
+    }
+
+    #[test]
+    fn it_should_return_error_for_zero_row_in_row_stochastic() {
+        let a = Matrix::new(vec![vec![1.0, 3.0], vec![0.0, 0.0]]).unwrap();
+        assert_eq!(
+            a.to_row_stochastic().unwrap_err(),
+            MathError::DivisionByZero
+        );
+    }
+
+    #[test]
+    fn it_should_compute_hadamard_product_correctly() {
+        let a = Matrix::new(vec![vec![1.0, 2.0], vec![3.0, 4.0]]).unwrap();
+        let b = Matrix::new(vec![vec![5.0, 6.0], vec![7.0, 8.0]]).unwrap();
+        assert_eq!(
+            a.hadamard(&b).unwrap().data,
+            vec![vec![5.0, 12.0], vec![21.0, 32.0]]
+        );
+        assert_eq!(
+            a.hadamard(&b).unwrap().hadamard_div(&b).unwrap().data,
+            a.data
+        );
+    }
+
+    #[test]
+    fn it_should_return_error_for_hadamard_division_by_zero() {
+        let a = Matrix::new(vec![vec![1.0, 2.0], vec![3.0, 4.0]]).unwrap();
+        let b = Matrix::new(vec![vec![1.0, 0.0], vec![1.0, 1.0]]).unwrap();
+        assert_eq!(a.hadamard_div(&b).unwrap_err(), MathError::DivisionByZero);
+        // Shape is validated before the divisor is scanned for zeros
+        let c = Matrix::new(vec![vec![0.0, 1.0]]).unwrap();
+        assert_eq!(
+            a.hadamard_div(&c).unwrap_err(),
+            MathError::ShapeMismatch("Matrix shapes must match")
+        );
+    }
+
+    #[test]
+    fn it_should_compute_pow_correctly() {
+        let a = Matrix::new(vec![vec![1.0, 1.0], vec![1.0, 0.0]]).unwrap();
+        assert_eq!(a.pow(0).unwrap().data, Matrix::identity(2).data);
+        assert_eq!(a.pow(1).unwrap().data, a.data);
+        assert_eq!(a.pow(2).unwrap().data, a.mul(&a).unwrap().data);
+        // Fibonacci: [[F(n+1), F(n)], [F(n), F(n-1)]]
+        assert_eq!(
+            a.pow(10).unwrap().data,
+            vec![vec![89.0, 55.0], vec![55.0, 34.0]]
+        );
+    }
+
+    #[test]
+    fn it_should_return_error_for_pow_of_non_square() {
+        let a = Matrix::new(vec![vec![1.0, 2.0, 3.0]]).unwrap();
+        assert_eq!(a.pow(2).unwrap_err(), MathError::NotSquare);
+    }
+
+    #[test]
+    fn it_should_compute_stationary_distribution_correctly() {
+        let p = Matrix::new(vec![vec![0.9, 0.1], vec![0.5, 0.5]]).unwrap();
+        let pi = p.stationary_distribution(1e-12, 10_000).unwrap();
+        assert!((pi[0] - 5.0 / 6.0).abs() < 1e-9);
+        assert!((pi[1] - 1.0 / 6.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn it_should_handle_periodic_chain_in_stationary_distribution() {
+        let p = Matrix::new(vec![vec![0.0, 1.0], vec![1.0, 0.0]]).unwrap();
+        let pi = p.stationary_distribution(1e-12, 10_000).unwrap();
+        assert!((pi[0] - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn it_should_accept_rounded_row_sums_with_tight_stationary_tolerance() {
+        // Ten entries of 0.1 sum to 0.9999999999999999 in floating point
+        let p = Matrix::new(vec![vec![0.1; 10]; 10]).unwrap();
+        assert!((p.data[0].iter().sum::<f64>() - 1.0).abs() > 1e-17);
+        let pi = p.stationary_distribution(1e-17, 100).unwrap();
+        assert!(pi.iter().all(|&x| (x - 0.1).abs() < 1e-15));
+    }
+
+    #[test]
+    fn it_should_accept_approximately_stochastic_rows_with_loose_tolerance() {
+        // Probabilities rounded to three places, so the first row sums to 0.999
+        let p = Matrix::new(vec![
+            vec![0.333, 0.333, 0.333],
+            vec![0.5, 0.25, 0.25],
+            vec![0.2, 0.3, 0.5],
+        ])
+        .unwrap();
+        assert_eq!(
+            p.stationary_distribution(1e-12, 10_000).unwrap_err(),
+            MathError::InvalidInput("Matrix must be row-stochastic")
+        );
+        let pi = p.stationary_distribution(1e-2, 10_000).unwrap();
+        assert!((pi.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+        assert!(pi.iter().all(|&x| x > 0.0));
+    }
+
+    #[test]
+    fn it_should_return_error_for_stationary_distribution_of_non_stochastic() {
+        let p = Matrix::new(vec![vec![0.9, 0.3], vec![0.5, 0.5]]).unwrap();
+        assert_eq!(
+            p.stationary_distribution(1e-12, 100).unwrap_err(),
+            MathError::InvalidInput("Matrix must be row-stochastic")
+        );
+    }
+
+    #[test]
+    fn it_should_compute_kron_correctly() {
+        let a = Matrix::new(vec![vec![1.0, 2.0], vec![3.0, 4.0]]).unwrap();
+        let b = Matrix::new(vec![vec![0.0, 5.0], vec![6.0, 7.0]]).unwrap();
+        let k = a.kron(&b);
+        assert_eq!(
+            k.data,
+            vec![
+                vec![0.0, 5.0, 0.0, 10.0],
+                vec![6.0, 7.0, 12.0, 14.0],
+                vec![0.0, 15.0, 0.0, 20.0],
+                vec![18.0, 21.0, 24.0, 28.0],
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_return_kron_dimensions_correctly() {
+        let a: Matrix = Matrix::zeros(2, 3);
+        let b = Matrix::zeros(4, 5);
+        let k = a.kron(&b);
+        assert_eq!((k.rows, k.cols), (8, 15));
+    }
+
+    #[test]
+    fn it_should_return_effective_rank_one_for_outer_product() {
+        let a = Matrix::new(vec![
+            vec![1.0, 2.0, 3.0],
+            vec![2.0, 4.0, 6.0],
+            vec![3.0, 6.0, 9.0],
+        ])
+        .unwrap();
+        let r = a.effective_rank(1e-12, 1000).unwrap();
+        assert!((r - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn it_should_return_full_effective_rank_for_equal_singular_values() {
+        let a = Matrix::identity(3).scale(2.0);
+        let r = a.effective_rank(1e-12, 1000).unwrap();
+        assert!((r - 3.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn it_should_compute_lu_correctly() {
+        let a = Matrix::new(vec![
+            vec![1.0, 2.0, 4.0],
+            vec![3.0, 8.0, 14.0],
+            vec![2.0, 6.0, 13.0],
+        ])
+        .unwrap();
+        let (l, u, perm) = a.lu().unwrap();
+        let pa: Vec<Vec<f64>> = perm.iter().map(|&i| a.data[i].clone()).collect();
+        assert_close(&l.mul(&u).unwrap(), &pa, 1e-12);
+    }
+
+    #[test]
+    fn it_should_solve_linear_system_correctly() {
+        let a = Matrix::new(vec![
+            vec![2.0, 1.0, -1.0],
+            vec![-3.0, -1.0, 2.0],
+            vec![-2.0, 1.0, 2.0],
+        ])
+        .unwrap();
+        let x = a.solve(&[8.0, -11.0, -3.0]).unwrap();
+        for (xi, ei) in x.iter().zip(&[2.0, 3.0, -1.0]) {
+            assert!((xi - ei).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn it_should_return_error_for_singular_solve() {
+        let a = Matrix::new(vec![
+            vec![1.0, 2.0, 3.0],
+            vec![2.0, 4.0, 6.0],
+            vec![1.0, 0.0, 1.0],
+        ])
+        .unwrap();
+        assert_eq!(a.solve(&[1.0, 2.0, 3.0]).unwrap_err(), MathError::Singular);
+        assert_eq!(
+            a.solve(&[1.0, 2.0]).unwrap_err(),
+            MathError::ShapeMismatch("Right-hand side length mismatch")
+        );
+    }
+
+    #[test]
+    fn it_should_contract_over_all_axis_combinations() {
+        let a = Matrix::new(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]).unwrap();
+        let b = Matrix::new(vec![vec![7.0, 8.0], vec![9.0, 10.0], vec![11.0, 12.0]]).unwrap();
+        let sq = Matrix::new(vec![vec![1.0, -1.0], vec![2.0, 0.5]]).unwrap();
+
+        let ab = a.contract(&b, Axis::Cols, Axis::Rows).unwrap();
+        assert_eq!(ab.data, a.mul(&b).unwrap().data);
+
+        let atb = a.contract(&sq, Axis::Rows, Axis::Rows).unwrap();
+        assert_eq!(atb.data, a.transpose().mul(&sq).unwrap().data);
+
+        let abt = a.contract(&a, Axis::Cols, Axis::Cols).unwrap();
+        assert_eq!(abt.data, a.mul(&a.transpose()).unwrap().data);
+
+        let atbt = a.contract(&b, Axis::Rows, Axis::Cols).unwrap();
+        assert_eq!(atbt.data, a.transpose().mul(&b.transpose()).unwrap().data);
+    }
+
+    #[test]
+    fn it_should_return_error_for_contract_dimension_mismatch() {
+        let a = Matrix::new(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]).unwrap();
+        let result = a.contract(&a, Axis::Cols, Axis::Rows);
+        assert_eq!(
+            result.unwrap_err(),
+            MathError::ShapeMismatch("Contracted dimensions must match")
+        );
+    }
+
+    #[test]
+    fn it_should_extract_submatrix_correctly() {
+        let a = Matrix::new(vec![
+            vec![1.0, 2.0, 3.0],
+            vec![4.0, 5.0, 6.0],
+            vec![7.0, 8.0, 9.0],
+        ])
+        .unwrap();
+        let s = a.submatrix(1..3, 1..3).unwrap();
+        assert_eq!((s.rows, s.cols), (2, 2));
+        assert_eq!(s.data, vec![vec![5.0, 6.0], vec![8.0, 9.0]]);
+    }
+
+    #[test]
+    fn it_should_return_error_for_submatrix_out_of_bounds() {
+        let a: Matrix = Matrix::identity(3);
+        assert_eq!(
+            a.submatrix(0..4, 0..2).unwrap_err(),
+            MathError::IndexOutOfBounds("Submatrix range out of bounds")
+        );
+        assert_eq!(
+            a.submatrix(1..1, 0..2).unwrap_err(),
+            MathError::Empty("Matrix cannot be empty")
+        );
+    }
+
+    #[test]
+    fn it_should_trace_elimination_to_row_echelon_form() {
+        let a = Matrix::new(vec![
+            vec![1.0, 2.0, 1.0],
+            vec![3.0, 8.0, 1.0],
+            vec![0.0, 4.0, 1.0],
+        ])
+        .unwrap();
+        let steps = a.elimination_trace();
+        assert_eq!(steps[0].0, "Initial matrix");
+        assert_eq!(steps[0].1.data, a.data);
+        assert_eq!(steps[1].0, "Swap R1 and R2");
+        assert_eq!(steps[2].0, "Pivot 3 at R1 C1");
+        assert!(
+            steps
+                .iter()
+                .any(|(d, _)| d.starts_with("Pivot") && d.ends_with("R2 C2"))
+        );
+        assert!(
+            steps
+                .iter()
+                .any(|(d, _)| d.starts_with("Pivot") && d.ends_with("R3 C3"))
+        );
+
+        let (_, last) = steps.last().unwrap();
+        let mut lead = None;
+        for row in &last.data {
+            let first = row.iter().position(|x| x.abs() > 1e-12);
+            if let (Some(prev), Some(cur)) = (lead, first) {
+                assert!(cur > prev);
+            }
+            lead = first;
+        }
+    }
+
+    #[test]
+    fn it_should_compute_determinant_correctly() {
+        let a = Matrix::new(vec![
+            vec![0.0, 2.0, 1.0],
+            vec![3.0, 1.0, 0.0],
+            vec![1.0, 1.0, 1.0],
+        ])
+        .unwrap();
+        assert!((a.determinant().unwrap() - -4.0).abs() < 1e-12);
+        assert_eq!(Matrix::<f64>::identity(4).determinant().unwrap(), 1.0);
+    }
+
+    #[test]
+    fn it_should_compute_minor_and_cofactor_correctly() {
+        let a = Matrix::new(vec![
+            vec![1.0, 2.0, 3.0],
+            vec![4.0, 5.0, 6.0],
+            vec![7.0, 8.0, 10.0],
+        ])
+        .unwrap();
+        // Remove row 0, col 1: det([[4, 6], [7, 10]]) = 40 - 42
+        assert!((a.minor(0, 1).unwrap() - -2.0).abs() < 1e-12);
+        assert!((a.cofactor(0, 1).unwrap() - 2.0).abs() < 1e-12);
+        for i in 0..3_usize {
+            for j in 0..3 {
+                let sign = if (i + j).is_multiple_of(2) { 1.0 } else { -1.0 };
+                assert_eq!(a.cofactor(i, j).unwrap(), sign * a.minor(i, j).unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn it_should_return_error_for_minor_of_too_small_matrix() {
+        let a = Matrix::new(vec![vec![5.0]]).unwrap();
+        assert_eq!(
+            a.minor(0, 0).unwrap_err(),
+            MathError::InvalidInput("Matrix must be at least 2x2")
+        );
+        assert_eq!(
+            Matrix::<f64>::identity(2).minor(2, 0).unwrap_err(),
+            MathError::IndexOutOfBounds("Index out of bounds")
+        );
+    }
+
+    #[test]
+    fn it_should_compute_adjugate_correctly() {
+        let a = Matrix::new(vec![
+            vec![2.0, -1.0, 0.0],
+            vec![1.0, 3.0, 2.0],
+            vec![0.0, 1.0, 4.0],
+        ])
+        .unwrap();
+        let adj = a.adjugate().unwrap();
+        let det = a.determinant().unwrap();
+        assert_close(
+            &a.mul(&adj).unwrap(),
+            &Matrix::identity(3).scale(det).data,
+            1e-10,
+        );
+
+        let b = Matrix::new(vec![vec![1.0, 2.0], vec![3.0, 4.0]]).unwrap();
+        assert_close(
+            &b.adjugate().unwrap(),
+            &[vec![4.0, -2.0], vec![-3.0, 1.0]],
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn it_should_invert_via_adjugate() {
+        let a = Matrix::new(vec![vec![4.0, 7.0], vec![2.0, 6.0]]).unwrap();
+        let inv = a.inverse_adjugate().unwrap();
+        assert_close(&inv, &[vec![0.6, -0.7], vec![-0.2, 0.4]], 1e-12);
+
+        let singular = Matrix::new(vec![vec![1.0, 2.0], vec![2.0, 4.0]]).unwrap();
+        assert_eq!(
+            singular.inverse_adjugate().unwrap_err(),
+            MathError::Singular
+        );
+    }
+
+    #[test]
+    fn it_should_compute_matrix_norms_correctly() {
+        let a = Matrix::new(vec![vec![1.0, -2.0], vec![-3.0, 4.0]]).unwrap();
+        assert!((a.frobenius_norm() - 30.0_f64.sqrt()).abs() < 1e-12);
+        assert_eq!(a.one_norm(), 6.0);
+        assert_eq!(a.inf_norm(), 7.0);
+
+        let flat = Vector(a.data.iter().flatten().copied().collect());
+        assert!((a.frobenius_norm() - flat.norm().unwrap()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn it_should_solve_nonsymmetric_system_with_gmres() {
+        let a = Matrix::new(vec![
+            vec![4.0, 1.0, 0.0, 2.0],
+            vec![-1.0, 5.0, 2.0, 0.0],
+            vec![0.0, -3.0, 6.0, 1.0],
+            vec![1.0, 0.0, -2.0, 7.0],
+        ])
+        .unwrap();
+        let b = [1.0, 2.0, 3.0, 4.0];
+        let direct = a.solve(&b).unwrap();
+        for restart in [2, 4] {
+            let x = a.solve_gmres(&b, restart, 1e-12, 500).unwrap();
+            for (xi, di) in x.iter().zip(&direct) {
+                assert!((xi - di).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn it_should_return_error_when_gmres_does_not_converge() {
+        let a = Matrix::new(vec![
+            vec![4.0, 1.0, 0.0],
+            vec![-1.0, 5.0, 2.0],
+            vec![0.0, -3.0, 6.0],
+        ])
+        .unwrap();
+        let result = a.solve_gmres(&[1.0, 2.0, 3.0], 1, 1e-14, 2);
+        assert_eq!(
+            result.unwrap_err(),
+            MathError::NotConverged("GMRES did not converge")
+        );
+        assert!(a.solve_gmres(&[1.0, 2.0], 2, 1e-8, 10).is_err());
+    }
+
+    #[test]
+    fn it_should_detect_symmetric_matrix() {
+        let s = Matrix::new(vec![vec![1.0, 2.0], vec![2.0, 3.0]]).unwrap();
+        assert!(s.is_symmetric(1e-12));
+        let a = Matrix::new(vec![vec![1.0, 2.0], vec![2.5, 3.0]]).unwrap();
+        assert!(!a.is_symmetric(1e-12));
+        assert!(!Matrix::zeros(2, 3).is_symmetric(1e-12));
+    }
+
+    #[test]
+    fn it_should_detect_orthogonal_matrix() {
+        assert!(Matrix::identity(3).is_orthogonal(1e-12));
+        let t = 0.3_f64;
+        let rot = Matrix::new(vec![vec![t.cos(), -t.sin()], vec![t.sin(), t.cos()]]).unwrap();
+        assert!(rot.is_orthogonal(1e-12));
+        let a = Matrix::new(vec![vec![1.0, 2.0], vec![3.0, 4.0]]).unwrap();
+        assert!(!a.is_orthogonal(1e-6));
+    }
+
+    #[test]
+    fn it_should_recover_nearest_orthogonal_matrix() {
+        let t = 0.7_f64;
+        let rot = Matrix::new(vec![
+            vec![t.cos(), -t.sin(), 0.0],
+            vec![t.sin(), t.cos(), 0.0],
+            vec![0.0, 0.0, 1.0],
+        ])
+        .unwrap();
+        let drift = Matrix::new(vec![
+            vec![1e-3, -2e-3, 5e-4],
+            vec![0.0, 1e-3, -1e-3],
+            vec![2e-3, 0.0, -5e-4],
+        ])
+        .unwrap();
+        let perturbed = rot.add(&drift).unwrap();
+        assert!(!perturbed.is_orthogonal(1e-6));
+
+        let q = perturbed.nearest_orthogonal(1e-14, 10_000).unwrap();
+        assert!(q.is_orthogonal(1e-8));
+        assert_close(&q, &rot.data, 5e-3);
+    }
+
+    #[test]
+    fn it_should_build_and_extract_diagonal() {
+        let d = Matrix::from_diagonal(&[1.0, 2.0, 3.0]);
+        assert_eq!((d.rows, d.cols), (3, 3));
+        assert_eq!(d.data[1], vec![0.0, 2.0, 0.0]);
+        assert_eq!(d.diagonal(), vec![1.0, 2.0, 3.0]);
+
+        let wide = Matrix::new(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]).unwrap();
+        assert_eq!(wide.diagonal(), vec![1.0, 5.0]);
+    }
+
+    #[test]
+    fn it_should_flatten_and_reshape_correctly() {
+        let a = Matrix::new(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]).unwrap();
+        assert_eq!(a.flatten(), vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let r = a.reshape(3, 2).unwrap();
+        assert_eq!((r.rows, r.cols), (3, 2));
+        assert_eq!(r.data, vec![vec![1.0, 2.0], vec![3.0, 4.0], vec![5.0, 6.0]]);
+        let back = Matrix::from_flat(r.flatten(), 2, 3).unwrap();
+        assert_eq!(back.data, a.data);
+    }
+
+    #[test]
+    fn it_should_return_error_for_reshape_length_mismatch() {
+        let a = Matrix::new(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]).unwrap();
+        assert_eq!(
+            a.reshape(4, 2).unwrap_err(),
+            MathError::ShapeMismatch("Data length must equal rows * cols")
+        );
+        assert!(Matrix::from_flat(vec![1.0; 5], 2, 3).is_err());
+    }
+
+    #[test]
+    fn it_should_index_and_mutate_entries() {
+        let mut m = Matrix::new(vec![vec![1.0, 2.0], vec![3.0, 4.0]]).unwrap();
+        assert_eq!(m[(0, 1)], 2.0);
+        assert_eq!(m[(1, 0)], 3.0);
+        m[(1, 0)] = 9.5;
+        m[(0, 0)] += 1.0;
+        assert_eq!(m.data, vec![vec![2.0, 2.0], vec![9.5, 4.0]]);
+        assert_eq!(
+            format!("{}", m),
+            "[  2.0000   2.0000]\n[  9.5000   4.0000]\n"
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn it_should_panic_on_out_of_bounds_index() {
+        let m: Matrix = Matrix::identity(2);
+        let _ = m[(2, 0)];
+    }
+
+    #[test]
+    fn it_should_converge_stochastic_powers_to_stationary_rows() {
+        let p = Matrix::new(vec![
+            vec![0.5, 0.3, 0.2],
+            vec![0.2, 0.6, 0.2],
+            vec![0.1, 0.2, 0.7],
+        ])
+        .unwrap();
+        let (limit, iters) = p.power_until_stable(1e-12, 100).unwrap();
+        assert!(iters > 1);
+        let pi = p.stationary_distribution(1e-14, 100_000).unwrap();
+        for row in &limit.data {
+            for (x, y) in row.iter().zip(&pi) {
+                assert!((x - y).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn it_should_return_error_when_powers_do_not_stabilize() {
+        let growing = Matrix::new(vec![vec![2.0, 0.0], vec![0.0, 1.0]]).unwrap();
+        assert_eq!(
+            growing.power_until_stable(1e-12, 5).unwrap_err(),
+            MathError::NotConverged("Matrix powers did not stabilize")
+        );
+    }
+
+    #[test]
+    fn it_should_support_matrix_operators() {
+        let a = Matrix::new(vec![vec![1.0, 2.0], vec![3.0, 4.0]]).unwrap();
+        let b = Matrix::new(vec![vec![0.0, 1.0], vec![1.0, 0.0]]).unwrap();
+        assert_eq!((&a + &b).data, a.add(&b).unwrap().data);
+        assert_eq!((&a - &b).data, a.sub(&b).unwrap().data);
+        assert_eq!((&a * &b).data, a.mul(&b).unwrap().data);
+        assert_eq!((&(&a * &b) + &a).data, vec![vec![3.0, 3.0], vec![7.0, 7.0]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Incompatible shapes for multiplication")]
+    fn it_should_panic_on_incompatible_mul_operator() {
+        let a: Matrix = Matrix::zeros(2, 3);
+        let _ = &a * &a;
+    }
+
+    #[test]
+    fn it_should_compute_inverse_correctly() {
+        let a = Matrix::new(vec![
+            vec![2.0, -1.0, 0.0],
+            vec![-1.0, 2.0, -1.0],
+            vec![0.0, -1.0, 2.0],
+        ])
+        .unwrap();
+        let inv = a.inverse().unwrap();
+        assert_close(&a.mul(&inv).unwrap(), &Matrix::identity(3).data, 1e-12);
+        assert_close(&inv, &a.inverse_adjugate().unwrap().data, 1e-12);
+    }
+
+    #[test]
+    fn it_should_format_with_precision_and_alignment() {
+        let m = Matrix::new(vec![vec![1.0, -2.5], vec![12345.678, 0.125]]).unwrap();
+        assert_eq!(m.format(2, 0), "[    1.00 -2.50]\n[12345.68  0.12]\n");
+        assert_eq!(
+            m.format(6, 0),
+            "[    1.000000 -2.500000]\n[12345.678000  0.125000]\n"
+        );
+        assert_eq!(
+            format!("{:.2}", m),
+            "[    1.00    -2.50]\n[12345.68     0.12]\n"
+        );
+    }
+
+    #[test]
+    fn it_should_keep_default_display_format() {
+        let m = Matrix::new(vec![vec![1.0, 2.0], vec![3.0, 4.0]]).unwrap();
+        assert_eq!(
+            format!("{}", m),
+            "[  1.0000   2.0000]\n[  3.0000   4.0000]\n"
+        );
+        let wide = Matrix::new(vec![vec![123456.0, 1.0], vec![1.0, 1.0]]).unwrap();
+        assert_eq!(
+            format!("{}", wide),
+            "[123456.0000   1.0000]\n[     1.0000   1.0000]\n"
+        );
+    }
+
+    #[test]
+    fn it_should_iterate_rows_and_cols() {
+        let m = Matrix::new(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]).unwrap();
+        let row_sums: Vec<f64> = m.rows().map(|r| r.iter().sum()).collect();
+        let col_sums: Vec<f64> = m.cols().map(|c| c.iter().sum()).collect();
+
+        let mut manual_rows = vec![0.0; m.rows];
+        let mut manual_cols = vec![0.0; m.cols];
+        for (i, row) in m.data.iter().enumerate() {
+            for (j, &x) in row.iter().enumerate() {
+                manual_rows[i] += x;
+                manual_cols[j] += x;
+            }
+        }
+        assert_eq!(row_sums, manual_rows);
+        assert_eq!(col_sums, manual_cols);
+        assert_eq!(col_sums, vec![5.0, 7.0, 9.0]);
+    }
+
+    #[test]
+    fn it_should_find_interior_eigenvalue_with_shift_invert() {
+        // Eigenvalues are 2 - √2, 2 and 2 + √2
+        let a = Matrix::new(vec![
+            vec![2.0, -1.0, 0.0],
+            vec![-1.0, 2.0, -1.0],
+            vec![0.0, -1.0, 2.0],
+        ])
+        .unwrap();
+        let (lambda, v) = a.eig_shift_invert(1.9, 1e-14, 1000).unwrap();
+        assert!((lambda - 2.0).abs() < 1e-10);
+        let av = a.mul_vec(&v).unwrap();
+        for (x, y) in av.iter().zip(&v) {
+            assert!((x - lambda * y).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn it_should_use_relative_tolerance_in_shift_invert() {
+        // Eigenvalues near 5e12 are only resolved to ~1e-3 absolutely, so the
+        // Rayleigh quotient never settles within an absolute tol
+        let base = Matrix::new(vec![
+            vec![4.1, 1.3, -0.7, 0.2],
+            vec![1.3, 2.9, 0.6, -1.1],
+            vec![-0.7, 0.6, 3.3, 0.8],
+            vec![0.2, -1.1, 0.8, 1.7],
+        ])
+        .unwrap();
+        let largest = base.jacobi_eigen(1e-15, 100).unwrap().0[0];
+        let a = base.scale(1e12);
+        let (lambda, _) = a
+            .eig_shift_invert((largest + 0.1) * 1e12, 1e-13, 2000)
+            .unwrap();
+        assert!((lambda / (largest * 1e12) - 1.0).abs() < 1e-12);
+        let (dominant, _) = a.dominant_eigenpair(1e-13, 2000).unwrap();
+        assert!((dominant / lambda - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn it_should_return_error_for_singular_shift() {
+        let a = Matrix::from_diagonal(&[1.0, 3.0]);
+        assert_eq!(
+            a.eig_shift_invert(3.0, 1e-12, 100).unwrap_err(),
+            MathError::Singular
+        );
+    }
+
+    #[test]
+    fn it_should_swap_rows_and_cols_in_place() {
+        let original = Matrix::new(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]).unwrap();
+        let mut m = original.clone();
+        m.swap_rows(0, 1).unwrap();
+        assert_eq!(m.data, vec![vec![4.0, 5.0, 6.0], vec![1.0, 2.0, 3.0]]);
+        m.swap_rows(0, 1).unwrap();
+        assert_eq!(m.data, original.data);
+
+        m.swap_cols(0, 2).unwrap();
+        assert_eq!(m.data, vec![vec![3.0, 2.0, 1.0], vec![6.0, 5.0, 4.0]]);
+        m.swap_cols(0, 2).unwrap();
+        assert_eq!(m.data, original.data);
+    }
+
+    #[test]
+    fn it_should_return_error_for_out_of_range_swap() {
+        let mut m: Matrix = Matrix::zeros(2, 3);
+        assert_eq!(
+            m.swap_rows(0, 2).unwrap_err(),
+            MathError::IndexOutOfBounds("Row index out of bounds")
+        );
+        assert_eq!(
+            m.swap_cols(3, 0).unwrap_err(),
+            MathError::IndexOutOfBounds("Column index out of bounds")
+        );
+    }
+
+    #[test]
+    fn it_should_sample_function_on_grid() {
+        let x = [0.0, 1.0, 2.0];
+        let y = [10.0, 20.0];
+        let m = Matrix::from_function_grid(&x, &y, |x, y| x + y);
+        assert_eq!((m.rows, m.cols), (2, 3));
+        assert_eq!(m[(0, 0)], 10.0);
+        assert_eq!(m[(0, 2)], 12.0);
+        assert_eq!(m[(1, 0)], 20.0);
+        assert_eq!(m[(1, 2)], 22.0);
+    }
+
+    #[test]
+    fn it_should_stack_matrices() {
+        let a = Matrix::new(vec![vec![1.0, 2.0], vec![3.0, 4.0]]).unwrap();
+        let b = Matrix::new(vec![vec![5.0, 6.0], vec![7.0, 8.0]]).unwrap();
+        let h = a.hstack(&b).unwrap();
+        assert_eq!((h.rows, h.cols), (2, 4));
+        assert_eq!(
+            h.data,
+            vec![vec![1.0, 2.0, 5.0, 6.0], vec![3.0, 4.0, 7.0, 8.0]]
+        );
+        let v = a.vstack(&b).unwrap();
+        assert_eq!((v.rows, v.cols), (4, 2));
+        assert_eq!(v.data[2], vec![5.0, 6.0]);
+    }
+
+    #[test]
+    fn it_should_return_error_for_stack_mismatch() {
+        let a: Matrix = Matrix::zeros(2, 2);
+        let b = Matrix::zeros(3, 1);
+        assert_eq!(
+            a.hstack(&b).unwrap_err(),
+            MathError::ShapeMismatch("Matrices must have the same number of rows")
+        );
+        assert_eq!(
+            a.vstack(&b).unwrap_err(),
+            MathError::ShapeMismatch("Matrices must have the same number of columns")
+        );
+    }
+
+    #[test]
+    fn it_should_build_idempotent_projection_matrix() {
+        let v = Vector(vec![1.0, 2.0, 2.0]);
+        let p = Matrix::projection_onto(&v).unwrap();
+        assert_close(&p.mul(&p).unwrap(), &p.data, 1e-12);
+
+        let x = Vector(vec![3.0, -1.0, 4.0]);
+        let px = p.mul_vec(&x.0).unwrap();
+        for (a, b) in px.iter().zip(&x.vector_projection(&v).unwrap().0) {
+            assert!((a - b).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn it_should_return_error_for_projection_onto_zero_vector() {
+        let v = Vector(vec![0.0, 0.0]);
+        assert_eq!(
+            Matrix::projection_onto(&v).unwrap_err(),
+            MathError::DivisionByZero
+        );
+    }
+
+    #[test]
+    fn it_should_assemble_block_matrix() {
+        let a = Matrix::identity(2);
+        let z = Matrix::zeros(2, 2);
+        let b = Matrix::new(vec![vec![1.0, 2.0], vec![3.0, 4.0]]).unwrap();
+        let m = Matrix::block(&[vec![a.clone(), b.clone()], vec![z, a]]).unwrap();
+        assert_eq!((m.rows, m.cols), (4, 4));
+        assert_eq!(
+            m.data,
+            vec![
+                vec![1.0, 0.0, 1.0, 2.0],
+                vec![0.0, 1.0, 3.0, 4.0],
+                vec![0.0, 0.0, 1.0, 0.0],
+                vec![0.0, 0.0, 0.0, 1.0],
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_return_error_for_block_height_mismatch() {
+        let a: Matrix = Matrix::identity(2);
+        let tall = Matrix::zeros(3, 2);
+        let result = Matrix::block(&[vec![a.clone(), tall], vec![a.clone(), a]]);
+        assert_eq!(
+            result.unwrap_err(),
+            MathError::ShapeMismatch("Blocks in a block row must have the same height")
+        );
+    }
+
+    #[test]
+    fn it_should_map_entries_preserving_shape() {
+        let m = Matrix::new(vec![vec![1.0, -2.0, 3.0], vec![-4.0, 5.0, -6.0]]).unwrap();
+        let sq = m.map(|x| x * x);
+        assert_eq!((sq.rows, sq.cols), (2, 3));
+        assert_eq!(sq.data, vec![vec![1.0, 4.0, 9.0], vec![16.0, 25.0, 36.0]]);
+        let abs = m.map(f64::abs);
+        assert_eq!((abs.rows, abs.cols), (2, 3));
+        assert_eq!(abs.data, vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]);
+    }
+
+    #[test]
+    fn it_should_match_direct_inverse_with_woodbury_update() {
+        let a = Matrix::new(vec![
+            vec![4.0, 1.0, 0.0],
+            vec![1.0, 3.0, 1.0],
+            vec![0.0, 1.0, 2.0],
+        ])
+        .unwrap();
+        let u = Matrix::new(vec![vec![1.0], vec![0.0], vec![2.0]]).unwrap();
+        let c = Matrix::new(vec![vec![0.5]]).unwrap();
+        let v = Matrix::new(vec![vec![0.0, 1.0, 1.0]]).unwrap();
+
+        let updated =
+            Matrix::woodbury_inverse_update(&a.inverse().unwrap(), &u, &c.inverse().unwrap(), &v)
+                .unwrap();
+        let direct = a
+            .add(&u.mul(&c).unwrap().mul(&v).unwrap())
+            .unwrap()
+            .inverse()
+            .unwrap();
+        assert_close(&updated, &direct.data, 1e-12);
+    }
+
+    #[test]
+    fn it_should_return_error_for_woodbury_shape_mismatch() {
+        let a_inv: Matrix = Matrix::identity(3);
+        let u = Matrix::zeros(3, 2);
+        let c_inv = Matrix::identity(1);
+        let v = Matrix::zeros(1, 3);
+        let result = Matrix::woodbury_inverse_update(&a_inv, &u, &c_inv, &v);
+        assert_eq!(
+            result.unwrap_err(),
+            MathError::ShapeMismatch("Incompatible shapes for Woodbury update")
+        );
+    }
+
+    #[test]
+    fn it_should_build_constant_and_generated_matrices() {
+        let c = Matrix::fill(2, 3, 7.5);
+        assert_eq!((c.rows, c.cols), (2, 3));
+        assert!(c.data.iter().flatten().all(|&x| x == 7.5));
+
+        let eye = Matrix::from_fn(3, 3, |i, j| (i == j) as i32 as f64);
+        assert_eq!(eye.data, Matrix::identity(3).data);
+
+        let mut m = Matrix::from_fn(2, 2, |i, j| (i * 2 + j) as f64);
+        assert_eq!(m.data, vec![vec![0.0, 1.0], vec![2.0, 3.0]]);
+        m.set_all(-1.0);
+        assert_eq!(m.data, Matrix::fill(2, 2, -1.0).data);
+    }
+
+    #[test]
+    fn it_should_generate_reproducible_random_matrices() {
+        let a = Matrix::random(4, 3, 42);
+        let b = Matrix::random(4, 3, 42);
+        let c = Matrix::random(4, 3, 43);
+        assert_eq!((a.rows, a.cols), (4, 3));
+        assert_eq!(a.data, b.data);
+        assert_ne!(a.data, c.data);
+        assert!(a.data.iter().flatten().all(|&x| (0.0..1.0).contains(&x)));
+    }
+
+    #[test]
+    fn it_should_reduce_to_hessenberg_form() {
+        let a = Matrix::new(vec![
+            vec![4.0, 1.0, -2.0, 2.0],
+            vec![1.0, 2.0, 0.0, 1.0],
+            vec![-2.0, 0.0, 3.0, -2.0],
+            vec![2.0, 1.0, -2.0, -1.0],
+        ])
+        .unwrap();
+        let h = a.to_hessenberg().unwrap();
+        for i in 2..4 {
+            for j in 0..i - 1 {
+                assert_eq!(h[(i, j)], 0.0);
+            }
+        }
+        assert!((h.trace().unwrap() - a.trace().unwrap()).abs() < 1e-10);
+        assert!((h.determinant().unwrap() - a.determinant().unwrap()).abs() < 1e-9);
+        assert!((h.frobenius_norm() - a.frobenius_norm()).abs() < 1e-10);
+    }
+
+    #[test]
+    fn it_should_return_error_for_hessenberg_of_non_square() {
+        let a: Matrix = Matrix::zeros(2, 3);
+        assert_eq!(a.to_hessenberg().unwrap_err(), MathError::NotSquare);
+    }
+
+    #[test]
+    fn it_should_match_inverse_with_pinv_for_square_matrix() {
+        let a = Matrix::new(vec![vec![4.0, 7.0], vec![2.0, 6.0]]).unwrap();
+        let pinv = a.pinv(1e-12, 10_000).unwrap();
+        assert_close(&pinv, &a.inverse().unwrap().data, 1e-8);
+    }
+
+    #[test]
+    fn it_should_satisfy_penrose_identity_for_tall_matrix() {
+        let a = Matrix::new(vec![vec![1.0, 2.0], vec![3.0, 4.0], vec![5.0, 6.0]]).unwrap();
+        let pinv = a.pinv(1e-12, 10_000).unwrap();
+        assert_eq!((pinv.rows, pinv.cols), (2, 3));
+        let apa = a.mul(&pinv).unwrap().mul(&a).unwrap();
+        assert_close(&apa, &a.data, 1e-8);
+    }
+
+    #[test]
+    fn it_should_compute_covariance_correctly() {
+        let data = Matrix::new(vec![
+            vec![1.0, 2.0],
+            vec![2.0, 4.0],
+            vec![3.0, 5.0],
+            vec![4.0, 9.0],
+        ])
+        .unwrap();
+        // Means (2.5, 5); var x = 5/3, var y = 26/3, cov = 11/3
+        let cov = data.covariance(1).unwrap();
+        assert_close(
+            &cov,
+            &[vec![5.0 / 3.0, 11.0 / 3.0], vec![11.0 / 3.0, 26.0 / 3.0]],
+            1e-12,
+        );
+        assert_eq!(cov.trace().unwrap(), 5.0 / 3.0 + 26.0 / 3.0);
+    }
+
+    #[test]
+    fn it_should_return_symmetric_covariance() {
+        let data: Matrix = Matrix::random(6, 3, 42);
+        let cov = data.covariance(0).unwrap();
+        assert_eq!((cov.rows, cov.cols), (3, 3));
+        assert!(cov.is_symmetric(0.0));
+        assert!(cov.diagonal().iter().all(|&v| v >= 0.0));
+    }
+
+    #[test]
+    fn it_should_return_error_for_covariance_with_too_few_rows() {
+        let data = Matrix::new(vec![vec![1.0, 2.0]]).unwrap();
+        assert_eq!(
+            data.covariance(1).unwrap_err(),
+            MathError::InvalidInput("Number of observations must exceed ddof")
+        );
+    }
+
+    #[test]
+    fn it_should_return_condition_number_correctly() {
+        let eye = Matrix::identity(3);
+        assert!((eye.condition_number(1e-12, 1000).unwrap() - 1.0).abs() < 1e-9);
+
+        let diag = Matrix::from_diagonal(&[10.0, 2.0, 0.5]);
+        assert!((diag.condition_number(1e-14, 10_000).unwrap() - 20.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn it_should_return_large_condition_number_for_near_singular() {
+        let a = Matrix::new(vec![vec![1.0, 1.0], vec![1.0, 1.0001]]).unwrap();
+        assert!(a.condition_number(1e-14, 100_000).unwrap() > 1e4);
+    }
+
+    #[test]
+    fn it_should_use_smallest_nonzero_singular_value_in_condition_number() {
+        // Rank one: the only nonzero singular value is 5
+        let singular = Matrix::new(vec![vec![1.0, 2.0], vec![2.0, 4.0]]).unwrap();
+        assert!((singular.condition_number(1e-12, 1000).unwrap() - 1.0).abs() < 1e-9);
+
+        let rank_two = Matrix::from_diagonal(&[4.0, 0.0, 0.5]);
+        assert!((rank_two.condition_number(1e-14, 10_000).unwrap() - 8.0).abs() < 1e-6);
+
+        let zero: Matrix = Matrix::zeros(2, 2);
+        assert_eq!(zero.condition_number(1e-12, 1000).unwrap(), f64::INFINITY);
+    }
+
+    #[test]
+    fn it_should_return_numerical_rank_correctly() {
+        let full = Matrix::new(vec![
+            vec![2.0, 1.0, 0.0],
+            vec![1.0, 3.0, 1.0],
+            vec![0.0, 1.0, 4.0],
+        ])
+        .unwrap();
+        assert_eq!(full.rank(1e-10, 10_000).unwrap(), 3);
+
+        let u = Matrix::new(vec![vec![1.0], vec![2.0], vec![3.0]]).unwrap();
+        let v = Matrix::new(vec![vec![4.0, 5.0, 6.0]]).unwrap();
+        let outer = u.mul(&v).unwrap();
+        assert_eq!(outer.rank(1e-10, 10_000).unwrap(), 1);
+        assert_eq!(Matrix::zeros(2, 2).rank(1e-10, 100).unwrap(), 0);
+    }
+
+    #[test]
+    fn it_should_satisfy_arnoldi_relation() {
+        let a = Matrix::random(6, 6, 7);
+        let k = 4;
+        let (q, h) = a.arnoldi(&[1.0, 0.0, 2.0, -1.0, 0.5, 3.0], k).unwrap();
+        assert_eq!((q.rows, q.cols), (6, k + 1));
+        assert_eq!((h.rows, h.cols), (k + 1, k));
+
+        let qk = q.submatrix(0..6, 0..k).unwrap();
+        let lhs = a.mul(&qk).unwrap();
+        let rhs = q.mul(&h).unwrap();
+        assert_close(&lhs, &rhs.data, 1e-10);
+        assert_close(
+            &q.transpose().mul(&q).unwrap(),
+            &Matrix::identity(k + 1).data,
+            1e-10,
+        );
+        for i in 2..k + 1 {
+            for j in 0..i - 1 {
+                assert_eq!(h[(i, j)], 0.0);
+            }
+        }
+    }
+
+    #[test]
+    fn it_should_return_error_for_arnoldi_length_mismatch() {
+        let a = Matrix::identity(3);
+        assert_eq!(
+            a.arnoldi(&[1.0, 0.0], 2).unwrap_err(),
+            MathError::ShapeMismatch("Start vector length mismatch")
+        );
+    }
+
+    #[test]
+    fn it_should_reconstruct_matrix_from_svd() {
+        let a = Matrix::new(vec![vec![3.0, 1.0, 1.0], vec![-1.0, 3.0, 1.0]]).unwrap();
+        let (u, s, vt) = a.svd(1e-14, 10_000).unwrap();
+        let rebuilt = Matrix::from_svd(&u, &s, &vt).unwrap();
+        assert_close(&rebuilt, &a.data, 1e-8);
+    }
+
+    #[test]
+    fn it_should_return_error_for_inconsistent_svd_factors() {
+        let u = Matrix::identity(2);
+        let vt = Matrix::identity(2);
+        let result = Matrix::from_svd(&u, &[1.0, 2.0, 3.0], &vt);
+        assert_eq!(
+            result.unwrap_err(),
+            MathError::ShapeMismatch("SVD factor shapes are inconsistent")
+        );
+    }
+
+    #[test]
+    fn it_should_return_svd_sorted_by_descending_singular_value() {
+        // Singular values are exactly 1, 4 and 2.5 by construction
+        let t = 0.4_f64;
+        let rot = Matrix::new(vec![
+            vec![t.cos(), -t.sin(), 0.0],
+            vec![t.sin(), t.cos(), 0.0],
+            vec![0.0, 0.0, 1.0],
+        ])
+        .unwrap();
+        let a = rot.mul(&Matrix::from_diagonal(&[1.0, 4.0, 2.5])).unwrap();
+        let (u, s, vt) = a.svd(1e-14, 10_000).unwrap();
+        assert_eq!(s.len(), 3);
+        assert!(s.windows(2).all(|w| w[0] >= w[1]));
+        for (got, want) in s.iter().zip(&[4.0, 2.5, 1.0]) {
+            assert!((got - want).abs() < 1e-8);
+        }
+        assert_close(&Matrix::from_svd(&u, &s, &vt).unwrap(), &a.data, 1e-8);
+    }
+
+    #[test]
+    fn it_should_return_full_orthogonal_factors_for_tall_matrix() {
+        let a = Matrix::new(vec![
+            vec![1.0, 2.0],
+            vec![3.0, 4.0],
+            vec![5.0, 6.0],
+            vec![7.0, 8.0],
+        ])
+        .unwrap();
+        let (u, s, vt) = a.svd_full(1e-14, 10_000).unwrap();
+        assert_eq!((u.rows, u.cols), (4, 4));
+        assert_eq!((vt.rows, vt.cols), (2, 2));
+        assert_eq!(s.len(), 2);
+        assert!(u.is_orthogonal(1e-8));
+        assert!(vt.is_orthogonal(1e-8));
+
+        let sigma = Matrix::from_fn(4, 2, |i, j| if i == j { s[i] } else { 0.0 });
+        let rebuilt = u.mul(&sigma).unwrap().mul(&vt).unwrap();
+        assert_close(&rebuilt, &a.data, 1e-8);
+    }
+
+    #[test]
+    fn it_should_complete_bases_for_rank_deficient_matrix() {
+        let a = Matrix::new(vec![vec![1.0, 2.0], vec![2.0, 4.0], vec![3.0, 6.0]]).unwrap();
+        let (u, s, vt) = a.svd_full(1e-14, 10_000).unwrap();
+        assert_eq!(s[1], 0.0);
+        assert!(u.is_orthogonal(1e-8));
+        assert!(vt.is_orthogonal(1e-8));
+    }
+
+    #[test]
+    fn it_should_converge_faster_with_jacobi_preconditioner() {
+        let n = 30;
+        let scale = |i: usize| 10f64.powi(i as i32 % 7 - 3);
+        let a = Matrix::from_fn(n, n, |i, j| {
+            let base = if i == j {
+                10.0
+            } else {
+                0.2 / (1.0 + i.abs_diff(j) as f64)
+            };
+            base * scale(i)
+        });
+        let b: Vec<f64> = (0..n).map(|i| scale(i) * (i as f64 + 1.0)).collect();
+
+        let (x_pre, pre_iters) = a.jacobi_gmres(&b, 1e-10, 500).unwrap();
+        let (_, plain_iters) = a.gmres(&b, n, 1e-10, 500).unwrap();
+        assert!(pre_iters < plain_iters, "{} vs {}", pre_iters, plain_iters);
+
+        let direct = a.solve(&b).unwrap();
+        for (p, d) in x_pre.iter().zip(&direct) {
+            assert!((p - d).abs() < 1e-8);
+        }
+        assert_eq!(a.solve_preconditioned(&b, 1e-10, 500).unwrap(), x_pre);
+    }
+
+    #[test]
+    fn it_should_return_error_for_zero_diagonal_preconditioner() {
+        let a = Matrix::new(vec![vec![0.0, 1.0], vec![1.0, 2.0]]).unwrap();
+        assert_eq!(
+            a.solve_preconditioned(&[1.0, 1.0], 1e-10, 100).unwrap_err(),
+            MathError::InvalidInput("Jacobi preconditioner requires a non-zero diagonal")
+        );
+    }
+
+    #[test]
+    fn it_should_report_svd_non_convergence() {
+        // Nearly equal singular values make power iteration converge slowly
+        let a = Matrix::from_diagonal(&[1.0, 0.999, 0.998]);
+        assert_eq!(
+            a.svd(1e-12, 3).unwrap_err(),
+            MathError::NotConverged("SVD did not converge within max_iter")
+        );
+        assert!(a.svd(1e-12, 1_000_000).is_ok());
+    }
+
+    #[test]
+    fn it_should_multiply_f32_matrices() {
+        let a: Matrix<f32> = Matrix::new(vec![vec![1.0, 2.0], vec![3.0, 4.0]]).unwrap();
+        let b: Matrix<f32> = Matrix::new(vec![vec![5.0, 6.0], vec![7.0, 8.0]]).unwrap();
+        let c = a.mul(&b).unwrap();
+        assert_eq!(c.data, vec![vec![19.0f32, 22.0], vec![43.0, 50.0]]);
+        assert_eq!((&a * &b).data, c.data);
+    }
+
+    #[test]
+    fn it_should_transpose_f32_matrices() {
+        let a: Matrix<f32> = Matrix::new(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]).unwrap();
+        let t = a.transpose();
+        assert_eq!((t.rows, t.cols), (3, 2));
+        assert_eq!(
+            t.data,
+            vec![vec![1.0f32, 4.0], vec![2.0, 5.0], vec![3.0, 6.0]]
+        );
+    }
+
+    #[test]
+    fn it_should_compute_f32_svd() {
+        let a: Matrix<f32> = Matrix::new(vec![vec![3.0, 0.0], vec![0.0, 4.0]]).unwrap();
+        let (u, s, vt) = a.svd(1e-6, 1000).unwrap();
+        assert!((s[0] - 4.0).abs() < 1e-4 && (s[1] - 3.0).abs() < 1e-4);
+        let rebuilt = Matrix::from_svd(&u, &s, &vt).unwrap();
+        for (row, expected) in rebuilt.data.iter().zip(&a.data) {
+            for (x, y) in row.iter().zip(expected) {
+                assert!((x - y).abs() < 1e-4);
+            }
+        }
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn it_should_match_mul_with_par_mul() {
+        let a: Matrix = Matrix::random(64, 64, 1);
+        let b: Matrix = Matrix::random(64, 64, 2);
+        assert_eq!(a.par_mul(&b).unwrap().data, a.mul(&b).unwrap().data);
+
+        let c: Matrix = Matrix::random(3, 5, 3);
+        let d: Matrix = Matrix::random(5, 2, 4);
+        assert_eq!(c.par_mul(&d).unwrap().data, c.mul(&d).unwrap().data);
+        assert!(c.par_mul(&c).is_err());
+    }
+
+    #[test]
+    fn it_should_match_mul_with_mul_blocked() {
+        let a: Matrix = Matrix::random(100, 100, 7);
+        let b: Matrix = Matrix::random(100, 100, 8);
+        let expected = a.mul(&b).unwrap().data;
+        assert_eq!(a.mul_blocked(&b, 16).unwrap().data, expected);
+        assert_eq!(a.mul_blocked(&b, 0).unwrap().data, expected);
+        assert_eq!(a.mul_blocked(&b, 33).unwrap().data, expected);
+
+        let c: Matrix = Matrix::random(3, 5, 9);
+        assert!(c.mul_blocked(&c, 2).is_err());
+    }
+
+    #[test]
+    fn it_should_return_matchable_error_variants() {
+        let rect = Matrix::new(vec![vec![1.0, 2.0, 3.0]]).unwrap();
+        let singular = Matrix::new(vec![vec![1.0, 2.0], vec![2.0, 4.0]]).unwrap();
+        assert!(matches!(rect.determinant(), Err(MathError::NotSquare)));
+        assert!(matches!(singular.inverse(), Err(MathError::Singular)));
+        assert!(matches!(rect.mul(&rect), Err(MathError::ShapeMismatch(_))));
+        assert!(matches!(
+            Matrix::from_diagonal(&[1.0, 0.999, 0.998]).svd(1e-14, 3),
+            Err(MathError::NotConverged(_))
+        ));
+        assert_eq!(
+            rect.mul(&rect).unwrap_err().to_string(),
+            "Incompatible shapes for multiplication"
+        );
+    }
+
+    #[test]
+    fn it_should_parse_matrix_from_str() {
+        let m: Matrix = "1 2; 3 4".parse().unwrap();
+        assert_eq!(m.data, vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+        let m: Matrix = "  -1.5   2e3 ;\n 0 4 ".parse().unwrap();
+        assert_eq!(m.data, vec![vec![-1.5, 2000.0], vec![0.0, 4.0]]);
+    }
+
+    #[test]
+    fn it_should_reject_malformed_matrix_strings() {
+        assert_eq!(
+            "1 2; 3".parse::<Matrix>().unwrap_err(),
+            MathError::ShapeMismatch("All rows must have the same number of columns")
+        );
+        assert_eq!(
+            "".parse::<Matrix>().unwrap_err(),
+            MathError::Empty("Matrix cannot be empty")
+        );
+        assert_eq!(
+            "1 2; 3 four".parse::<Matrix>().unwrap_err(),
+            MathError::Parse { line: 2, column: 2 }
+        );
+    }
+
+    #[test]
+    fn it_should_count_empty_segments_when_parsing_matrix_strings() {
+        assert_eq!(
+            "1 2;;3 4".parse::<Matrix>().unwrap_err(),
+            MathError::ShapeMismatch("All rows must have the same number of columns")
+        );
+        assert_eq!(
+            "1 2;;3 x".parse::<Matrix>().unwrap_err(),
+            MathError::Parse { line: 3, column: 2 }
+        );
+        let m: Matrix = "1 2; 3 4;".parse().unwrap();
+        assert_eq!(m.data, vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+    }
+
+    #[test]
+    fn it_should_find_dominant_eigenpair() {
+        // Eigenvalues 6 and ±√3, with the dominant eigenvector along (1, 1, 1)
+        let a = Matrix::new(vec![
+            vec![2.0, 1.0, 3.0],
+            vec![1.0, 3.0, 2.0],
+            vec![3.0, 2.0, 1.0],
+        ])
+        .unwrap();
+        let (lambda, v) = a.dominant_eigenpair(1e-12, 1000).unwrap();
+        assert!((lambda - 6.0).abs() < 1e-9);
+        let expected = 1.0 / 3.0_f64.sqrt();
+        for x in &v {
+            assert!((x.abs() - expected).abs() < 1e-5);
+        }
+        let av = a.mul_vec(&v).unwrap();
+        for (x, y) in av.iter().zip(&v) {
+            assert!((x - lambda * y).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn it_should_reject_non_square_dominant_eigenpair() {
+        let a = Matrix::new(vec![vec![1.0, 2.0, 3.0]]).unwrap();
+        assert_eq!(
+            a.dominant_eigenpair(1e-10, 100).unwrap_err(),
+            MathError::NotSquare
+        );
+    }
+
+    #[test]
+    fn it_should_compute_symmetric_eigenvalues_with_qr() {
+        let a = Matrix::new(vec![
+            vec![2.0, -1.0, 0.0],
+            vec![-1.0, 2.0, -1.0],
+            vec![0.0, -1.0, 2.0],
+        ])
+        .unwrap();
+        let eigs = a.eigenvalues(1e-12, 1000).unwrap();
+        let s = 2.0_f64.sqrt();
+        for (x, y) in eigs.iter().zip([2.0 + s, 2.0, 2.0 - s]) {
+            assert!((x - y).abs() < 1e-9);
+        }
+
+        let b = Matrix::new(vec![vec![4.0, 1.0], vec![2.0, 3.0]]).unwrap();
+        let eigs = b.eigenvalues(1e-12, 1000).unwrap();
+        assert!((eigs[0] - 5.0).abs() < 1e-9 && (eigs[1] - 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn it_should_return_the_diagonal_as_eigenvalues() {
+        let a = Matrix::from_diagonal(&[5.0, -1.0, 3.0, 0.5]);
+        assert_eq!(a.eigenvalues(1e-12, 10).unwrap(), vec![5.0, 3.0, 0.5, -1.0]);
+        assert_eq!(
+            Matrix::new(vec![vec![1.0, 2.0]])
+                .unwrap()
+                .eigenvalues(1e-12, 10)
+                .unwrap_err(),
+            MathError::NotSquare
+        );
+    }
+
+    #[test]
+    fn it_should_approximate_complex_eigenvalues_by_diagonal_blocks() {
+        // Rotation by 90°: eigenvalues ±i, reported as the block's diagonal
+        let a = Matrix::new(vec![vec![0.0, -1.0], vec![1.0, 0.0]]).unwrap();
+        assert_eq!(a.eigenvalues(1e-12, 100).unwrap(), vec![0.0, 0.0]);
+    }
+
+    #[test]
+    fn it_should_reconstruct_symmetric_matrix_from_jacobi_eigen() {
+        let a = Matrix::new(vec![
+            vec![4.0, 1.0, -2.0, 2.0],
+            vec![1.0, 2.0, 0.0, 1.0],
+            vec![-2.0, 0.0, 3.0, -2.0],
+            vec![2.0, 1.0, -2.0, -1.0],
+        ])
+        .unwrap();
+        let (eigs, q) = a.jacobi_eigen(1e-12, 100).unwrap();
+        assert!(eigs.windows(2).all(|w| w[0] >= w[1]));
+        assert!(q.is_orthogonal(1e-10));
+
+        let rebuilt = q
+            .mul(&Matrix::from_diagonal(&eigs))
+            .unwrap()
+            .mul(&q.transpose())
+            .unwrap();
+        assert_close(&rebuilt, &a.data, 1e-10);
+        let qr_eigs = a.eigenvalues(1e-12, 1000).unwrap();
+        for (x, y) in eigs.iter().zip(&qr_eigs) {
+            assert!((x - y).abs() < 1e-8);
+        }
+    }
+
+    #[test]
+    fn it_should_accept_round_off_asymmetry_in_jacobi_eigen() {
+        // R·D·Rᵀ comes out symmetric only up to round-off
+        let r = Matrix::<f64>::givens(4, 0, 1, 0.3)
+            .unwrap()
+            .mul(&Matrix::givens(4, 1, 2, 0.7).unwrap())
+            .unwrap()
+            .mul(&Matrix::givens(4, 2, 3, 1.1).unwrap())
+            .unwrap();
+        let d = Matrix::from_diagonal(&[3.7, -1.3, 0.21, 2.9]);
+        let a = r.mul(&d).unwrap().mul(&r.transpose()).unwrap();
+        assert!(!a.is_symmetric(0.0));
+
+        let (eigs, _) = a.jacobi_eigen(1e-16, 100).unwrap();
+        for (x, y) in eigs.iter().zip([3.7, 2.9, 0.21, -1.3]) {
+            assert!((x - y).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn it_should_reject_non_symmetric_jacobi_eigen() {
+        let a = Matrix::new(vec![vec![1.0, 2.0], vec![3.0, 4.0]]).unwrap();
+        assert_eq!(
+            a.jacobi_eigen(1e-10, 100).unwrap_err(),
+            MathError::InvalidInput("Matrix must be symmetric")
+        );
+    }
+
+    #[test]
+    fn it_should_compute_rref() {
+        let a = Matrix::new(vec![
+            vec![1.0, 2.0, -1.0, -4.0],
+            vec![2.0, 3.0, -1.0, -11.0],
+            vec![-2.0, 0.0, -3.0, 22.0],
+        ])
+        .unwrap();
+        assert_close(
+            &a.rref(1e-12),
+            &[
+                vec![1.0, 0.0, 0.0, -8.0],
+                vec![0.0, 1.0, 0.0, 1.0],
+                vec![0.0, 0.0, 1.0, -2.0],
+            ],
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn it_should_match_svd_rank_with_rank_rref() {
+        let cases = [
+            Matrix::identity(3),
+            Matrix::new(vec![
+                vec![1.0, 2.0, 3.0],
+                vec![2.0, 4.0, 6.0],
+                vec![1.0, 0.0, 1.0],
+            ])
+            .unwrap(),
+            Matrix::new(vec![vec![1.0, 2.0], vec![2.0, 4.0], vec![3.0, 6.0]]).unwrap(),
+            Matrix::new(vec![vec![0.0, 1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0, 7.0]]).unwrap(),
+            Matrix::zeros(2, 3),
+        ];
+        let expected = [3, 2, 1, 2, 0];
+        for (m, &r) in cases.iter().zip(&expected) {
+            assert_eq!(m.rank_rref(1e-10), r);
+            assert_eq!(m.rank(1e-10, 10_000).unwrap(), r);
+        }
+    }
+
+    #[test]
+    fn it_should_check_invertibility() {
+        assert!(Matrix::<f64>::identity(3).is_invertible(1e-12));
+        let duplicated = Matrix::new(vec![
+            vec![1.0, 2.0, 3.0],
+            vec![4.0, 5.0, 6.0],
+            vec![1.0, 2.0, 3.0],
+        ])
+        .unwrap();
+        assert!(!duplicated.is_invertible(1e-12));
+        assert!(
+            !Matrix::new(vec![vec![1.0, 2.0]])
+                .unwrap()
+                .is_invertible(1e-12)
+        );
+        let tiny = Matrix::from_diagonal(&[1.0, 1e-9]);
+        assert!(tiny.is_invertible(1e-12));
+        assert!(!tiny.is_invertible(1e-6));
+    }
+
+    #[test]
+    fn it_should_match_cross_product_with_skew() {
+        let a = [1.0, -2.0, 3.0];
+        let b = [4.0, 0.5, -1.0];
+        let s = Matrix::skew(&a).unwrap();
+        let expected = Vector(a.to_vec()).cross(&Vector(b.to_vec())).unwrap();
+        assert_eq!(s.mul_vec(&b).unwrap(), expected.0);
+
+        let neg_t = s.transpose().scale(-1.0);
+        assert_eq!(neg_t.data, s.data);
+        assert_eq!(s.diagonal(), vec![0.0; 3]);
+        assert!(Matrix::skew(&[1.0, 2.0]).is_err());
+    }
+
+    #[test]
+    fn it_should_build_householder_reflections() {
+        let v = [1.0, -2.0, 2.0];
+        let h = Matrix::householder(&v).unwrap();
+        assert!(h.is_orthogonal(1e-12));
+        assert!(h.is_symmetric(1e-12));
+        let hv = h.mul_vec(&v).unwrap();
+        for (x, y) in hv.iter().zip(&v) {
+            assert!((x + y).abs() < 1e-12);
+        }
+        // Vectors orthogonal to v are left unchanged
+        let w = [2.0, 1.0, 0.0];
+        assert_eq!(h.mul_vec(&w).unwrap(), w.to_vec());
+        assert_eq!(
+            Matrix::householder(&[0.0, 0.0]).unwrap_err(),
+            MathError::DivisionByZero
+        );
+    }
+
+    #[test]
+    fn it_should_zero_a_component_with_givens() {
+        let x = [3.0, 1.0, 4.0, 2.0];
+        let g = Matrix::givens(4, 0, 2, (-x[2]).atan2(x[0])).unwrap();
+        assert!(g.is_orthogonal(1e-12));
+        let gx = g.mul_vec(&x).unwrap();
+        assert!(gx[2].abs() < 1e-12);
+        assert!((gx[0] - 5.0).abs() < 1e-12);
+        assert_eq!((gx[1], gx[3]), (1.0, 2.0));
+
+        assert_eq!(
+            Matrix::<f64>::givens(3, 0, 3, 0.1).unwrap_err(),
+            MathError::IndexOutOfBounds("Index out of bounds")
+        );
+        assert!(Matrix::<f64>::givens(3, 1, 1, 0.1).is_err());
+    }
+
+    #[test]
+    fn it_should_solve_triangular_systems() {
+        // 2x = 4, x + 3y = 11, -x + y + 4z = 5  =>  (2, 3, 1)
+        let l = Matrix::new(vec![
+            vec![2.0, 0.0, 0.0],
+            vec![1.0, 3.0, 0.0],
+            vec![-1.0, 1.0, 4.0],
+        ])
+        .unwrap();
+        assert_eq!(
+            l.solve_lower(&[4.0, 11.0, 5.0]).unwrap(),
+            vec![2.0, 3.0, 1.0]
+        );
+
+        // x + 2y - z = 3, 2y + z = 7, 5z = 15  =>  (4, 2, 3)
+        let u = Matrix::new(vec![
+            vec![1.0, 2.0, -1.0],
+            vec![0.0, 2.0, 1.0],
+            vec![0.0, 0.0, 5.0],
+        ])
+        .unwrap();
+        assert_eq!(
+            u.solve_upper(&[5.0, 7.0, 15.0]).unwrap(),
+            vec![4.0, 2.0, 3.0]
+        );
+    }
+
+    #[test]
+    fn it_should_reject_invalid_triangular_systems() {
+        let zero_diag = Matrix::new(vec![vec![1.0, 0.0], vec![2.0, 0.0]]).unwrap();
+        assert_eq!(
+            zero_diag.solve_lower(&[1.0, 2.0]).unwrap_err(),
+            MathError::Singular
+        );
+        let rect = Matrix::new(vec![vec![1.0, 2.0]]).unwrap();
+        assert_eq!(rect.solve_upper(&[1.0]).unwrap_err(), MathError::NotSquare);
+        assert!(Matrix::<f64>::identity(2).solve_upper(&[1.0]).is_err());
+    }
+
+    #[test]
+    fn it_should_compute_correlation() {
+        // Column 1 = 2·x + 1, column 2 = -x, column 3 is unrelated noise
+        let data = Matrix::new(vec![
+            vec![1.0, 3.0, -1.0, 0.5],
+            vec![2.0, 5.0, -2.0, -1.0],
+            vec![4.0, 9.0, -4.0, 2.0],
+            vec![7.0, 15.0, -7.0, 0.0],
+        ])
+        .unwrap();
+        let corr = data.correlation().unwrap();
+        assert_eq!(corr.diagonal(), vec![1.0; 4]);
+        assert!((corr[(0, 1)] - 1.0).abs() < 1e-12);
+        assert!((corr[(0, 2)] + 1.0).abs() < 1e-12);
+        assert!((corr[(1, 2)] + 1.0).abs() < 1e-12);
+        assert!(corr[(0, 3)].abs() < 1.0);
+        assert!(corr.is_symmetric(1e-15));
+    }
+
+    #[test]
+    fn it_should_reject_zero_variance_correlation() {
+        let data = Matrix::new(vec![vec![1.0, 5.0], vec![2.0, 5.0], vec![3.0, 5.0]]).unwrap();
+        assert_eq!(
+            data.correlation().unwrap_err(),
+            MathError::InvalidInput("Columns must have non-zero variance")
+        );
+    }
+
+    #[test]
+    fn it_should_center_and_standardize_columns() {
+        let m = Matrix::new(vec![vec![1.0, 10.0], vec![2.0, 30.0], vec![6.0, 20.0]]).unwrap();
+        let centered = m.center_columns();
+        for j in 0..2 {
+            let mean = centered.data.iter().map(|r| r[j]).sum::<f64>() / 3.0;
+            assert!(mean.abs() < 1e-12);
+        }
+
+        let z = m.standardize_columns().unwrap();
+        for j in 0..2 {
+            let mean = z.data.iter().map(|r| r[j]).sum::<f64>() / 3.0;
+            let var = z.data.iter().map(|r| r[j] * r[j]).sum::<f64>() / 3.0;
+            assert!(mean.abs() < 1e-12);
+            assert!((var - 1.0).abs() < 1e-12);
+        }
+
+        let constant = Matrix::new(vec![vec![1.0, 5.0], vec![2.0, 5.0]]).unwrap();
+        assert_eq!(
+            constant.standardize_columns().unwrap_err(),
+            MathError::InvalidInput("Columns must have non-zero variance")
+        );
+    }
+
+    #[test]
+    fn it_should_find_the_dominant_axis_with_pca() {
+        // Spread mostly along x with a little noise in y
+        let m = Matrix::new(vec![
+            vec![-4.0, 0.1],
+            vec![-2.0, -0.2],
+            vec![0.0, 0.05],
+            vec![2.0, 0.2],
+            vec![4.0, -0.15],
+        ])
+        .unwrap();
+        let (components, variance) = m.pca(2, 1e-10, 1000).unwrap();
+        assert_eq!((components.rows, components.cols), (2, 2));
+        assert!(components.data[0][0].abs() > 0.99);
+        assert!(components.data[1][1].abs() > 0.99);
+        assert!(variance[0] > 100.0 * variance[1]);
+        // Total variance is preserved: var(x) = 40/4, var(y) = 0.115/4
+        assert!((variance[0] + variance[1] - 10.02875).abs() < 1e-6);
+
+        assert_eq!(
+            m.pca(3, 1e-10, 1000).unwrap_err(),
+            MathError::InvalidInput("Number of components exceeds number of columns")
+        );
+    }
+
+    #[test]
+    fn it_should_fit_a_line_with_normal_equations() {
+        // y ≈ 1 + 2x with noise; the least-squares fit is y = 1.06 + 1.97x
+        let xs = [0.0, 1.0, 2.0, 3.0, 4.0];
+        let ys = [1.1, 2.9, 5.2, 6.8, 9.0];
+        let a = Matrix::new(xs.iter().map(|&x| vec![1.0, x]).collect()).unwrap();
+        let coef = a.lstsq_normal(&ys).unwrap();
+        assert!((coef[0] - 1.06).abs() < 1e-10);
+        assert!((coef[1] - 1.97).abs() < 1e-10);
+
+        assert_eq!(
+            a.lstsq_normal(&[1.0]).unwrap_err(),
+            MathError::ShapeMismatch("Right-hand side length mismatch")
+        );
+    }
+
+    #[test]
+    fn it_should_beat_normal_equations_with_qr_least_squares() {
+        // Läuchli matrix: AᵀA has condition number ~2/ε², so the normal
+        // equations lose about twice as many digits as QR
+        let eps = 1e-4;
+        let a = Matrix::new(vec![vec![1.0, 1.0], vec![eps, 0.0], vec![0.0, eps]]).unwrap();
+        let b = [3.0, eps, 2.0 * eps];
+        let err = |x: &[f64]| ((x[0] - 1.0).powi(2) + (x[1] - 2.0).powi(2)).sqrt();
+
+        let qr_err = err(&a.lstsq_qr(&b).unwrap());
+        let normal_err = err(&a.lstsq_normal(&b).unwrap());
+        assert!(qr_err < 1e-12);
+        assert!(normal_err > 1e-10);
+
+        let rank_deficient =
+            Matrix::new(vec![vec![1.0, 2.0], vec![2.0, 4.0], vec![3.0, 6.0]]).unwrap();
+        assert_eq!(
+            rank_deficient.lstsq_qr(&[1.0, 2.0, 3.0]).unwrap_err(),
+            MathError::InvalidInput("Matrix must have full column rank")
+        );
+        assert_eq!(
+            Matrix::new(vec![vec![1.0, 2.0]])
+                .unwrap()
+                .lstsq_qr(&[1.0])
+                .unwrap_err(),
+            MathError::InvalidInput("QR requires rows >= cols")
+        );
+    }
+
+    #[test]
+    fn it_should_shrink_ridge_solutions_as_lambda_grows() {
+        let a = Matrix::new(vec![
+            vec![1.0, 0.5],
+            vec![1.0, 1.5],
+            vec![1.0, 3.0],
+            vec![1.0, 4.0],
+        ])
+        .unwrap();
+        let b = [1.2, 2.9, 6.1, 8.0];
+        assert_eq!(a.ridge(&b, 0.0).unwrap(), a.lstsq_normal(&b).unwrap());
+
+        let norm = |x: &[f64]| x.iter().map(|v| v * v).sum::<f64>().sqrt();
+        let mut prev = f64::INFINITY;
+        for lambda in [0.0, 0.1, 1.0, 10.0, 100.0] {
+            let n = norm(&a.ridge(&b, lambda).unwrap());
+            assert!(n < prev);
+            prev = n;
+        }
+
+        // Rank-deficient columns are solvable once regularized
+        let dup = Matrix::new(vec![vec![1.0, 1.0], vec![2.0, 2.0]]).unwrap();
+        assert!(dup.lstsq_normal(&[1.0, 2.0]).is_err());
+        let x = dup.ridge(&[1.0, 2.0], 1e-3).unwrap();
+        assert!((x[0] - x[1]).abs() < 1e-12);
+        assert_eq!(
+            a.ridge(&b, -1.0).unwrap_err(),
+            MathError::InvalidInput("Regularization parameter must be non-negative")
+        );
+    }
+
+    #[test]
+    fn it_should_compare_matrices_within_tolerance() {
+        let a = Matrix::new(vec![vec![1.0, 2.0], vec![3.0, 4.0]]).unwrap();
+        let b = Matrix::new(vec![vec![1.0, 2.0], vec![3.0 + 1e-10, 4.0]]).unwrap();
+        assert!(a.approx_eq(&b, 1e-6));
+        assert!(!a.approx_eq(&b, 1e-12));
+        let row = Matrix::new(vec![vec![1.0, 2.0]]).unwrap();
+        assert!(!a.approx_eq(&row, 10.0));
+
+        let (u, s, vt) = a.svd(1e-12, 10_000).unwrap();
+        assert!(a.approx_eq(&Matrix::from_svd(&u, &s, &vt).unwrap(), 1e-8));
+    }
+
+    #[test]
+    fn it_should_format_mixed_magnitudes_in_scientific_notation() {
+        let m = Matrix::new(vec![vec![1e-9, 1e9], vec![-2.5, 0.0]]).unwrap();
+        // Fixed-point loses the small entry entirely
+        assert_eq!(
+            m.format(4, 0),
+            "[ 0.0000 1000000000.0000]\n[-2.5000          0.0000]\n"
+        );
+        assert_eq!(m.to_string_sci(2), "[1.00e-9 1.00e9]\n[-2.50e0 0.00e0]\n");
+        assert_eq!(format!("{:.2e}", m), m.to_string_sci(2));
+        assert_eq!(format!("{:e}", m), "[  1e-9 1e9]\n[-2.5e0 0e0]\n");
+        assert_eq!(
+            format!("{:8.1e}", m),
+            "[  1.0e-9    1.0e9]\n[  -2.5e0    0.0e0]\n"
+        );
+    }
+
+    #[test]
+    fn it_should_render_latex_and_markdown() {
+        let m = Matrix::new(vec![vec![1.0, -2.5], vec![0.125, 4.0]]).unwrap();
+        assert_eq!(
+            m.to_latex(2),
+            "\\begin{bmatrix}\n  1.00 & -2.50 \\\\\n  0.12 & 4.00\n\\end{bmatrix}"
+        );
+        assert_eq!(
+            m.to_markdown(1),
+            "| C1 | C2 |\n| --- | --- |\n| 1.0 | -2.5 |\n| 0.1 | 4.0 |\n"
+        );
+    }
+
+    #[test]
+    fn it_should_report_lu_permutation_parity() {
+        // The zero pivot forces exactly one row swap
+        let swapped = Matrix::new(vec![vec![0.0, 1.0], vec![2.0, 3.0]]).unwrap();
+        let (_, u, perm, parity) = swapped.lu_parity().unwrap();
+        assert_eq!(perm, vec![1, 0]);
+        assert_eq!(parity, -1);
+        let det = f64::from(parity) * u.data[0][0] * u.data[1][1];
+        assert!((det - swapped.determinant().unwrap()).abs() < 1e-12);
+        assert!((det + 2.0).abs() < 1e-12);
+
+        let (_, _, perm, parity) = Matrix::<f64>::identity(3).lu_parity().unwrap();
+        assert_eq!(perm, vec![0, 1, 2]);
+        assert_eq!(parity, 1);
+        assert_eq!(
+            Matrix::new(vec![vec![1.0, 2.0]])
+                .unwrap()
+                .lu_parity()
+                .unwrap_err(),
+            MathError::NotSquare
+        );
+    }
+
+    #[test]
+    fn it_should_build_and_apply_permutations() {
+        let perm = [2, 0, 1];
+        let p: Matrix = Matrix::permutation(&perm).unwrap();
+        assert!(p.is_orthogonal(1e-12));
+
+        let a = Matrix::new(vec![vec![1.0, 2.0], vec![3.0, 4.0], vec![5.0, 6.0]]).unwrap();
+        let expected = vec![vec![5.0, 6.0], vec![1.0, 2.0], vec![3.0, 4.0]];
+        assert_eq!(a.permute_rows(&perm).unwrap().data, expected);
+        assert_eq!(p.mul(&a).unwrap().data, expected);
+
+        // lu's permutation follows the same convention: P·A = L·U
+        let m = Matrix::new(vec![
+            vec![0.0, 1.0, 2.0],
+            vec![3.0, 1.0, 0.0],
+            vec![1.0, 4.0, 1.0],
+        ])
+        .unwrap();
+        let (l, u, lu_perm) = m.lu().unwrap();
+        assert!(
+            m.permute_rows(&lu_perm)
+                .unwrap()
+                .approx_eq(&l.mul(&u).unwrap(), 1e-12)
+        );
+
+        for bad in [&[0, 0, 1][..], &[0, 3, 1][..]] {
+            assert_eq!(
+                Matrix::<f64>::permutation(bad).unwrap_err(),
+                MathError::InvalidInput("Index array must be a permutation of 0..n")
+            );
+        }
+        assert!(a.permute_rows(&[1, 0]).is_err());
+    }
+
+    #[test]
+    fn it_should_compute_trace_of_product_without_multiplying() {
+        let a = Matrix::new(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]).unwrap();
+        let b = Matrix::new(vec![vec![7.0, -1.0], vec![0.5, 2.0], vec![3.0, 1.0]]).unwrap();
+        let expected = a.mul(&b).unwrap().trace().unwrap();
+        assert!((a.trace_of_product(&b).unwrap() - expected).abs() < 1e-12);
+        // tr(A·B) = tr(B·A) even though the products differ in size
+        assert!((b.trace_of_product(&a).unwrap() - expected).abs() < 1e-12);
+
+        assert_eq!(
+            a.trace_of_product(&a).unwrap_err(),
+            MathError::ShapeMismatch("Incompatible shapes for multiplication")
+        );
+        let c = Matrix::new(vec![vec![1.0], vec![2.0], vec![3.0]]).unwrap();
+        assert_eq!(a.trace_of_product(&c).unwrap_err(), MathError::NotSquare);
+    }
+
+    #[test]
+    fn it_should_detect_diagonal_and_triangular_structure() {
+        let d = Matrix::from_diagonal(&[2.0, 1e-14, -3.0]);
+        assert!(d.is_diagonal(1e-12));
+        assert!(d.is_upper_triangular(1e-12));
+        assert!(d.is_lower_triangular(1e-12));
+
+        let mut u = Matrix::new(vec![
+            vec![1.0, 2.0, 3.0],
+            vec![0.0, 4.0, 5.0],
+            vec![0.0, 0.0, 6.0],
+        ])
+        .unwrap();
+        u.data[2][0] = 1e-15;
+        assert!(u.is_upper_triangular(1e-12));
+        assert!(!u.is_upper_triangular(0.0));
+        assert!(!u.is_lower_triangular(1e-12));
+        assert!(!u.is_diagonal(1e-12));
+        assert!(u.transpose().is_lower_triangular(1e-12));
+
+        let dense = Matrix::new(vec![vec![1.0, 2.0], vec![3.0, 4.0]]).unwrap();
+        assert!(!dense.is_diagonal(1e-12));
+        assert!(!dense.is_upper_triangular(1e-12));
+        assert!(!dense.is_lower_triangular(1e-12));
+
+        let wide = Matrix::new(vec![vec![1.0, 0.0, 0.0]]).unwrap();
+        assert!(!wide.is_diagonal(1e-12));
+        assert!(!wide.is_upper_triangular(1e-12));
+    }
 }
@@ -0,0 +1,70 @@
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+use nalgebra::{DMatrix, DVector, Scalar};
+use num_traits::Float;
+
+// nalgebra stores column-major, so copies go through `(row, col)` indexing
+// rather than the raw buffers
+
+impl<T: Float + Scalar> From<&Matrix<T>> for DMatrix<T> {
+    fn from(m: &Matrix<T>) -> Self {
+        DMatrix::from_fn(m.rows, m.cols, |i, j| m.data[i][j])
+    }
+}
+
+impl<T: Float + Scalar> From<&DMatrix<T>> for Matrix<T> {
+    fn from(m: &DMatrix<T>) -> Self {
+        Matrix::from_fn(m.nrows(), m.ncols(), |i, j| m[(i, j)])
+    }
+}
+
+impl<T: Float + Scalar> From<&Vector<T>> for DVector<T> {
+    fn from(v: &Vector<T>) -> Self {
+        DVector::from_column_slice(&v.0)
+    }
+}
+
+impl<T: Float + Scalar> From<&DVector<T>> for Vector<T> {
+    fn from(v: &DVector<T>) -> Self {
+        Vector(v.iter().copied().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_convert_matrix_to_dmatrix() {
+        let m = Matrix::new(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]).unwrap();
+        let d = DMatrix::from(&m);
+        assert_eq!(d.shape(), (2, 3));
+        assert_eq!(d[(0, 2)], 3.0);
+        assert_eq!(d[(1, 0)], 4.0);
+    }
+
+    #[test]
+    fn it_should_round_trip_through_dmatrix() {
+        let m = Matrix::new(vec![vec![1.5, -2.0], vec![0.0, 4.0], vec![7.0, 8.0]]).unwrap();
+        let back = Matrix::from(&DMatrix::from(&m));
+        assert_eq!((back.rows, back.cols), (3, 2));
+        assert_eq!(back.data, m.data);
+    }
+
+    #[test]
+    fn it_should_round_trip_through_dvector() {
+        let v = Vector(vec![1.0, -2.5, 3.0]);
+        let d = DVector::from(&v);
+        assert_eq!(d.len(), 3);
+        assert_eq!(d[1], -2.5);
+        assert_eq!(Vector::from(&d), v);
+    }
+
+    #[test]
+    fn it_should_agree_with_nalgebra_products() {
+        let a = Matrix::new(vec![vec![1.0, 2.0], vec![3.0, 4.0], vec![5.0, 6.0]]).unwrap();
+        let b = Matrix::new(vec![vec![1.0, 0.0, -1.0], vec![2.0, 1.0, 0.5]]).unwrap();
+        let expected = DMatrix::from(&a) * DMatrix::from(&b);
+        assert_eq!(Matrix::from(&expected).data, a.mul(&b).unwrap().data);
+    }
+}
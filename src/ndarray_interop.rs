@@ -0,0 +1,65 @@
+use crate::error::MathError;
+use crate::matrix::Matrix;
+use alloc::vec::Vec;
+use ndarray::Array2;
+use num_traits::Float;
+
+/// `Array2` is indexed `[row, col]` like `Matrix`, so an m×n array maps to an
+/// m×n matrix regardless of the array's memory layout. Arrays with no rows or
+/// no columns are rejected like any other empty `Matrix`
+impl<T: Float> TryFrom<Array2<T>> for Matrix<T> {
+    type Error = MathError;
+
+    fn try_from(a: Array2<T>) -> Result<Self, MathError> {
+        Matrix::new(a.outer_iter().map(|row| row.to_vec()).collect())
+    }
+}
+
+impl<T: Float> From<&Matrix<T>> for Array2<T> {
+    fn from(m: &Matrix<T>) -> Self {
+        let flat: Vec<T> = m.flatten();
+        Array2::from_shape_vec((m.rows, m.cols), flat)
+            .expect("Matrix rows and cols must match its data")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::array;
+
+    #[test]
+    fn it_should_convert_array2_to_matrix() {
+        let a = array![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+        let m = Matrix::try_from(a).unwrap();
+        assert_eq!((m.rows, m.cols), (2, 3));
+        assert_eq!(m.data, vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]);
+    }
+
+    #[test]
+    fn it_should_respect_column_major_arrays() {
+        let a = array![[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]].reversed_axes();
+        let m = Matrix::try_from(a).unwrap();
+        assert_eq!((m.rows, m.cols), (2, 3));
+        assert_eq!(m.data, vec![vec![1.0, 3.0, 5.0], vec![2.0, 4.0, 6.0]]);
+    }
+
+    #[test]
+    fn it_should_round_trip_through_array2() {
+        let m = Matrix::new(vec![vec![1.5, -2.0], vec![0.0, 4.0], vec![7.0, 8.0]]).unwrap();
+        let a = Array2::from(&m);
+        assert_eq!(a.dim(), (3, 2));
+        assert_eq!(a[[2, 0]], 7.0);
+        assert_eq!(Matrix::try_from(a).unwrap().data, m.data);
+    }
+
+    #[test]
+    fn it_should_reject_empty_array2() {
+        for a in [Array2::<f64>::zeros((0, 3)), Array2::<f64>::zeros((2, 0))] {
+            assert_eq!(
+                Matrix::try_from(a).unwrap_err(),
+                MathError::Empty("Matrix cannot be empty")
+            );
+        }
+    }
+}
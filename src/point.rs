@@ -1,4 +1,4 @@
-use std::fmt;
+use core::fmt;
 
 pub struct Point {
     pub(crate) x: i32,
@@ -7,6 +7,6 @@ pub struct Point {
 
 impl fmt::Display for Point {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "({},{})\n", self.x, self.y)
+        writeln!(f, "({},{})", self.x, self.y)
     }
 }
@@ -0,0 +1,231 @@
+use crate::error::MathError;
+use crate::matrix::Matrix;
+use alloc::vec;
+use alloc::vec::Vec;
+use num_traits::Float;
+
+/// Sparse matrix in compressed sparse row (CSR) format.
+///
+/// The non-zeros of row `i` are `values[row_ptr[i]..row_ptr[i + 1]]`, stored
+/// with their column indices in `col_idx` in ascending column order. Fields
+/// are private so that invariant cannot be broken from outside.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SparseMatrix<T = f64> {
+    rows: usize,
+    cols: usize,
+    row_ptr: Vec<usize>,
+    col_idx: Vec<usize>,
+    values: Vec<T>,
+}
+
+impl<T: Float> SparseMatrix<T> {
+    /// Builds from `(row, col, value)` entries; duplicates are summed and
+    /// entries that end up zero are dropped
+    pub fn from_triplets(
+        rows: usize,
+        cols: usize,
+        triplets: &[(usize, usize, T)],
+    ) -> Result<Self, MathError> {
+        if rows == 0 || cols == 0 {
+            return Err(MathError::Empty("Matrix cannot be empty"));
+        }
+        if triplets.iter().any(|&(i, j, _)| i >= rows || j >= cols) {
+            return Err(MathError::IndexOutOfBounds("Triplet index out of bounds"));
+        }
+        let mut sorted = triplets.to_vec();
+        sorted.sort_by_key(|&(i, j, _)| (i, j));
+        let mut merged: Vec<(usize, usize, T)> = Vec::with_capacity(sorted.len());
+        for (i, j, v) in sorted {
+            match merged.last_mut() {
+                Some(last) if (last.0, last.1) == (i, j) => last.2 = last.2 + v,
+                _ => merged.push((i, j, v)),
+            }
+        }
+        merged.retain(|&(_, _, v)| v != T::zero());
+
+        let mut row_ptr = vec![0; rows + 1];
+        for &(i, _, _) in &merged {
+            row_ptr[i + 1] += 1;
+        }
+        for i in 0..rows {
+            row_ptr[i + 1] += row_ptr[i];
+        }
+        let col_idx = merged.iter().map(|&(_, j, _)| j).collect();
+        let values = merged.iter().map(|&(_, _, v)| v).collect();
+        Ok(Self {
+            rows,
+            cols,
+            row_ptr,
+            col_idx,
+            values,
+        })
+    }
+
+    pub fn identity(n: usize) -> Self {
+        Self {
+            rows: n,
+            cols: n,
+            row_ptr: (0..=n).collect(),
+            col_idx: (0..n).collect(),
+            values: vec![T::one(); n],
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Number of stored non-zero entries
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> T {
+        let range = self.row_ptr[row]..self.row_ptr[row + 1];
+        match self.col_idx[range.clone()].binary_search(&col) {
+            Ok(k) => self.values[range.start + k],
+            Err(_) => T::zero(),
+        }
+    }
+
+    pub fn mul_vec(&self, v: &[T]) -> Result<Vec<T>, MathError> {
+        if self.cols != v.len() {
+            return Err(MathError::ShapeMismatch(
+                "Incompatible shapes for matrix-vector multiplication",
+            ));
+        }
+        Ok((0..self.rows)
+            .map(|i| {
+                (self.row_ptr[i]..self.row_ptr[i + 1]).fold(T::zero(), |acc, k| {
+                    acc + self.values[k] * v[self.col_idx[k]]
+                })
+            })
+            .collect())
+    }
+
+    pub fn to_dense(&self) -> Matrix<T> {
+        let mut m = Matrix::zeros(self.rows, self.cols);
+        for i in 0..self.rows {
+            for k in self.row_ptr[i]..self.row_ptr[i + 1] {
+                m.data[i][self.col_idx[k]] = self.values[k];
+            }
+        }
+        m
+    }
+
+    pub fn from_dense(m: &Matrix<T>) -> Self {
+        let mut row_ptr = Vec::with_capacity(m.rows + 1);
+        let mut col_idx = Vec::new();
+        let mut values = Vec::new();
+        row_ptr.push(0);
+        for row in &m.data {
+            for (j, &x) in row.iter().enumerate() {
+                if x != T::zero() {
+                    col_idx.push(j);
+                    values.push(x);
+                }
+            }
+            row_ptr.push(values.len());
+        }
+        Self {
+            rows: m.rows,
+            cols: m.cols,
+            row_ptr,
+            col_idx,
+            values,
+        }
+    }
+}
+
+impl<T: Float> From<&Matrix<T>> for SparseMatrix<T> {
+    fn from(m: &Matrix<T>) -> Self {
+        Self::from_dense(m)
+    }
+}
+
+impl<T: Float> From<&SparseMatrix<T>> for Matrix<T> {
+    fn from(s: &SparseMatrix<T>) -> Self {
+        s.to_dense()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_build_sparse_identity() {
+        let s: SparseMatrix = SparseMatrix::identity(3);
+        assert_eq!(s.nnz(), 3);
+        assert_eq!(s.to_dense().data, Matrix::<f64>::identity(3).data);
+        assert_eq!(s.mul_vec(&[1.0, 2.0, 3.0]).unwrap(), vec![1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn it_should_match_dense_mul_vec() {
+        let s = SparseMatrix::from_triplets(
+            3,
+            4,
+            &[
+                (0, 1, 2.0),
+                (2, 3, -1.0),
+                (1, 0, 4.0),
+                (2, 0, 0.5),
+                (0, 1, 1.0),
+            ],
+        )
+        .unwrap();
+        assert_eq!(s.nnz(), 4);
+        assert_eq!(s.get(0, 1), 3.0);
+        assert_eq!(s.get(1, 1), 0.0);
+
+        let v = [1.0, -2.0, 3.0, 0.5];
+        assert_eq!(s.mul_vec(&v).unwrap(), s.to_dense().mul_vec(&v).unwrap());
+        assert!(s.mul_vec(&[1.0]).is_err());
+    }
+
+    #[test]
+    fn it_should_drop_entries_that_cancel() {
+        let s =
+            SparseMatrix::from_triplets(2, 2, &[(0, 0, 1.0), (0, 0, -1.0), (1, 1, 2.0)]).unwrap();
+        assert_eq!(s.nnz(), 1);
+        assert_eq!(s.to_dense().data, vec![vec![0.0, 0.0], vec![0.0, 2.0]]);
+    }
+
+    #[test]
+    fn it_should_reject_out_of_bounds_triplets() {
+        assert_eq!(
+            SparseMatrix::from_triplets(2, 2, &[(2, 0, 1.0)]).unwrap_err(),
+            MathError::IndexOutOfBounds("Triplet index out of bounds")
+        );
+    }
+
+    #[test]
+    fn it_should_reject_empty_dimensions() {
+        for (rows, cols) in [(0, 0), (0, 3), (2, 0)] {
+            assert_eq!(
+                SparseMatrix::<f64>::from_triplets(rows, cols, &[]).unwrap_err(),
+                MathError::Empty("Matrix cannot be empty")
+            );
+        }
+        let s = SparseMatrix::from_triplets(2, 3, &[(1, 2, 4.0)]).unwrap();
+        assert_eq!((s.rows(), s.cols()), (2, 3));
+    }
+
+    #[test]
+    fn it_should_round_trip_dense_and_sparse() {
+        let m = Matrix::new(vec![
+            vec![0.0, 1.5, 0.0],
+            vec![0.0, 0.0, 0.0],
+            vec![-2.0, 0.0, 3.0],
+        ])
+        .unwrap();
+        let s = SparseMatrix::from(&m);
+        assert_eq!(s.nnz(), 3);
+        assert_eq!(Matrix::from(&s).data, m.data);
+    }
+}
@@ -1,6 +1,11 @@
+use crate::error::MathError;
+use crate::rng::SplitMix64;
+use alloc::string::ToString;
+use alloc::vec;
+use alloc::vec::Vec;
 use core::fmt;
+use core::ops::{Add, Div, Mul, Sub};
 use num_traits::Float;
-use std::ops::{Add, Div, Mul, Sub};
 
 #[allow(dead_code)]
 pub enum VectorOp {
@@ -24,21 +29,21 @@ where
         + PartialEq,
 {
     #[allow(dead_code)]
-    pub fn add(&self, other: &Self) -> Result<Self, &'static str> {
+    pub fn add(&self, other: &Self) -> Result<Self, MathError> {
         operate_vectors(&self.0, &other.0, VectorOp::Add)
     }
 
     #[allow(dead_code)]
-    pub fn sub(&self, other: &Self) -> Result<Self, &'static str> {
+    pub fn sub(&self, other: &Self) -> Result<Self, MathError> {
         operate_vectors(&self.0, &other.0, VectorOp::Sub)
     }
     #[allow(dead_code)]
-    pub fn mul(&self, other: &Self) -> Result<Self, &'static str> {
+    pub fn mul(&self, other: &Self) -> Result<Self, MathError> {
         operate_vectors(&self.0, &other.0, VectorOp::Mul)
     }
 
     #[allow(dead_code)]
-    pub fn div(&self, other: &Self) -> Result<Self, &'static str> {
+    pub fn div(&self, other: &Self) -> Result<Self, MathError> {
         operate_vectors(&self.0, &other.0, VectorOp::Div)
     }
 
@@ -46,6 +51,31 @@ where
     pub fn len(&self) -> usize {
         self.0.len()
     }
+
+    #[allow(dead_code)]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    #[allow(dead_code)]
+    pub fn dot_prefix(&self, other: &Self) -> Result<Self, MathError> {
+        if self.0.len() != other.0.len() {
+            return Err(MathError::ShapeMismatch(
+                "Vectors must have the same length",
+            ));
+        }
+        let mut acc = T::default();
+        let result = self
+            .0
+            .iter()
+            .zip(other.0.iter())
+            .map(|(&x, &y)| {
+                acc = acc + x * y;
+                acc
+            })
+            .collect();
+        Ok(Vector(result))
+    }
 }
 
 impl<T> Vector<T>
@@ -53,9 +83,11 @@ where
     T: Float + fmt::Display,
 {
     #[allow(dead_code)]
-    pub fn dot_product(&self, other: &Self) -> Result<T, &'static str> {
+    pub fn dot_product(&self, other: &Self) -> Result<T, MathError> {
         if self.0.len() != other.0.len() {
-            return Err("Vectors must have the same length");
+            return Err(MathError::ShapeMismatch(
+                "Vectors must have the same length",
+            ));
         }
         let result = self
             .0
@@ -66,10 +98,39 @@ where
         Ok(result)
     }
 
+    /// Σ wᵢ·aᵢ·bᵢ; uniform unit weights give the ordinary dot product
     #[allow(dead_code)]
-    pub fn norm(&self) -> Result<T, &'static str> {
-        if self.0.len() == 0 {
-            return Err("Vector must have at least one element");
+    pub fn weighted_dot(&self, other: &Self, weights: &Self) -> Result<T, MathError> {
+        if self.0.len() != other.0.len() || self.0.len() != weights.0.len() {
+            return Err(MathError::ShapeMismatch(
+                "Vectors must have the same length",
+            ));
+        }
+        let result = self
+            .0
+            .iter()
+            .zip(other.0.iter())
+            .zip(weights.0.iter())
+            .fold(T::zero(), |acc, ((&x, &y), &w)| acc + w * x * y);
+
+        Ok(result)
+    }
+
+    /// Element-wise |aᵢ - bᵢ| <= tol; vectors of different lengths are never equal
+    #[allow(dead_code)]
+    pub fn approx_eq(&self, other: &Self, tol: T) -> bool {
+        self.0.len() == other.0.len()
+            && self
+                .0
+                .iter()
+                .zip(other.0.iter())
+                .all(|(&x, &y)| (x - y).abs() <= tol)
+    }
+
+    #[allow(dead_code)]
+    pub fn norm(&self) -> Result<T, MathError> {
+        if self.0.is_empty() {
+            return Err(MathError::Empty("Vector must have at least one element"));
         }
         let result = self.0.iter().fold(T::zero(), |acc, &x| acc + x * x);
 
@@ -77,14 +138,14 @@ where
     }
 
     #[allow(dead_code)]
-    pub fn unit_vect(&self) -> Result<Self, &'static str> {
-        if self.0.len() == 0 {
-            return Err("Vector must have at least one element");
+    pub fn unit_vect(&self) -> Result<Self, MathError> {
+        if self.0.is_empty() {
+            return Err(MathError::Empty("Vector must have at least one element"));
         }
 
         let norm = self.norm()?;
         if norm == T::zero() {
-            return Err("Cannot normalize zero vector");
+            return Err(MathError::DivisionByZero);
         }
         let result = self.0.iter().map(|&x| x / norm).collect();
 
@@ -92,9 +153,11 @@ where
     }
 
     #[allow(dead_code)]
-    pub fn scalar_projection(&self, other: &Self) -> Result<T, &'static str> {
+    pub fn scalar_projection(&self, other: &Self) -> Result<T, MathError> {
         if self.0.len() != other.0.len() {
-            return Err("Vectors must have the same length");
+            return Err(MathError::ShapeMismatch(
+                "Vectors must have the same length",
+            ));
         }
 
         let b_unit = other.unit_vect()?;
@@ -104,9 +167,11 @@ where
     }
 
     #[allow(dead_code)]
-    pub fn cosine_similarity(&self, other: &Self) -> Result<T, &'static str> {
+    pub fn cosine_similarity(&self, other: &Self) -> Result<T, MathError> {
         if self.0.len() != other.0.len() {
-            return Err("Vectors must have the same length");
+            return Err(MathError::ShapeMismatch(
+                "Vectors must have the same length",
+            ));
         }
 
         let dot = self.dot_product(other)?;
@@ -114,11 +179,335 @@ where
         let norm_b = other.norm()?;
 
         if norm_a == T::zero() || norm_b == T::zero() {
-            return Err("Cannot compute cosine similarity with zero vector");
+            return Err(MathError::DivisionByZero);
         }
 
         Ok(dot / (norm_a * norm_b))
     }
+
+    #[allow(dead_code)]
+    pub fn cross(&self, other: &Self) -> Result<Self, MathError> {
+        if self.0.len() != 3 || other.0.len() != 3 {
+            return Err(MathError::ShapeMismatch(
+                "Cross product requires 3-dimensional vectors",
+            ));
+        }
+        let (a, b) = (&self.0, &other.0);
+        Ok(Vector(vec![
+            a[1] * b[2] - a[2] * b[1],
+            a[2] * b[0] - a[0] * b[2],
+            a[0] * b[1] - a[1] * b[0],
+        ]))
+    }
+
+    #[allow(dead_code)]
+    pub fn interp_at(&self, pos: T) -> Result<T, MathError> {
+        if self.0.is_empty() {
+            return Err(MathError::Empty("Vector must have at least one element"));
+        }
+        let last = T::from(self.0.len() - 1).unwrap();
+        if !(pos >= T::zero() && pos <= last) {
+            return Err(MathError::IndexOutOfBounds("Position out of range"));
+        }
+
+        let i = pos.floor().to_usize().unwrap();
+        if i + 1 >= self.0.len() {
+            return Ok(self.0[i]);
+        }
+        let frac = pos - pos.floor();
+        Ok(self.0[i] + (self.0[i + 1] - self.0[i]) * frac)
+    }
+
+    #[allow(dead_code)]
+    pub fn moving_mean(&self, window: usize) -> Result<Self, MathError> {
+        let n = T::from(window).unwrap();
+        self.rolling_apply(window, |w| w.iter().fold(T::zero(), |acc, &x| acc + x) / n)
+    }
+
+    /// Population standard deviation of each window
+    #[allow(dead_code)]
+    pub fn moving_std(&self, window: usize) -> Result<Self, MathError> {
+        let means = self.moving_mean(window)?;
+        let n = T::from(window).unwrap();
+        let result = self
+            .0
+            .windows(window)
+            .zip(means.0.iter())
+            .map(|(w, &m)| {
+                let var = w.iter().fold(T::zero(), |acc, &x| acc + (x - m) * (x - m)) / n;
+                var.sqrt()
+            })
+            .collect();
+        Ok(Vector(result))
+    }
+
+    /// Cross-entropy H(self, target) = -Σ targetᵢ ln(selfᵢ), with self the predicted distribution
+    #[allow(dead_code)]
+    pub fn cross_entropy(&self, target: &Self) -> Result<T, MathError> {
+        if self.0.len() != target.0.len() {
+            return Err(MathError::ShapeMismatch(
+                "Vectors must have the same length",
+            ));
+        }
+        if self.0.iter().chain(target.0.iter()).any(|&x| x < T::zero()) {
+            return Err(MathError::InvalidInput(
+                "Probabilities must be non-negative",
+            ));
+        }
+        let eps = T::from(1e-12).unwrap();
+        let result = self
+            .0
+            .iter()
+            .zip(target.0.iter())
+            .fold(T::zero(), |acc, (&p, &t)| acc - t * p.max(eps).ln());
+        Ok(result)
+    }
+
+    /// KL(self || other) = Σ selfᵢ ln(selfᵢ / otherᵢ); other must cover the support of self
+    #[allow(dead_code)]
+    pub fn kl_divergence(&self, other: &Self) -> Result<T, MathError> {
+        if self.0.len() != other.0.len() {
+            return Err(MathError::ShapeMismatch(
+                "Vectors must have the same length",
+            ));
+        }
+        if self.0.iter().chain(other.0.iter()).any(|&x| x < T::zero()) {
+            return Err(MathError::InvalidInput(
+                "Probabilities must be non-negative",
+            ));
+        }
+        self.0
+            .iter()
+            .zip(other.0.iter())
+            .try_fold(T::zero(), |acc, (&p, &q)| {
+                if p == T::zero() {
+                    Ok(acc)
+                } else if q == T::zero() {
+                    Err(MathError::InvalidInput(
+                        "Support of other must cover support of self",
+                    ))
+                } else {
+                    Ok(acc + p * (p / q).ln())
+                }
+            })
+    }
+
+    #[allow(dead_code)]
+    pub fn dot_iter<I>(&self, other: I) -> Result<T, MathError>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut other = other.into_iter();
+        let mut result = T::zero();
+        for &x in &self.0 {
+            match other.next() {
+                Some(y) => result = result + x * y,
+                None => {
+                    return Err(MathError::ShapeMismatch(
+                        "Vectors must have the same length",
+                    ));
+                }
+            }
+        }
+        if other.next().is_some() {
+            return Err(MathError::ShapeMismatch(
+                "Vectors must have the same length",
+            ));
+        }
+        Ok(result)
+    }
+
+    /// Shannon entropy in nats, with 0 * ln 0 taken as 0
+    #[allow(dead_code)]
+    pub fn entropy(&self) -> Result<T, MathError> {
+        if self.0.iter().any(|&p| p < T::zero()) {
+            return Err(MathError::InvalidInput(
+                "Probabilities must be non-negative",
+            ));
+        }
+        let result = self
+            .0
+            .iter()
+            .filter(|&&p| p > T::zero())
+            .fold(T::zero(), |acc, &p| acc - p * p.ln());
+        Ok(result)
+    }
+
+    #[allow(dead_code)]
+    pub fn entropy_base(&self, base: T) -> Result<T, MathError> {
+        if base <= T::one() {
+            return Err(MathError::InvalidInput("Log base must be greater than 1"));
+        }
+        Ok(self.entropy()? / base.ln())
+    }
+
+    #[allow(dead_code)]
+    pub fn vector_projection(&self, other: &Self) -> Result<Self, MathError> {
+        let denom = other.dot_product(other)?;
+        if denom == T::zero() {
+            return Err(MathError::DivisionByZero);
+        }
+        let coef = self.dot_product(other)? / denom;
+        Ok(Vector(other.0.iter().map(|&x| x * coef).collect()))
+    }
+
+    /// Z-scores using the population standard deviation; also returns the
+    /// fitted (mean, std) so the same transform can be reapplied to new data
+    #[allow(dead_code)]
+    pub fn standardize(&self) -> Result<(Self, T, T), MathError> {
+        if self.0.is_empty() {
+            return Err(MathError::Empty("Vector must have at least one element"));
+        }
+        let n = T::from(self.0.len()).unwrap();
+        let mean = self.0.iter().fold(T::zero(), |acc, &x| acc + x) / n;
+        let var = self
+            .0
+            .iter()
+            .fold(T::zero(), |acc, &x| acc + (x - mean) * (x - mean))
+            / n;
+        let std = var.sqrt();
+        let scaled = self.apply_standardization(mean, std)?;
+        Ok((scaled, mean, std))
+    }
+
+    #[allow(dead_code)]
+    pub fn apply_standardization(&self, mean: T, std: T) -> Result<Self, MathError> {
+        if std == T::zero() {
+            return Err(MathError::DivisionByZero);
+        }
+        Ok(Vector(self.0.iter().map(|&x| (x - mean) / std).collect()))
+    }
+}
+
+impl<T> Vector<T> {
+    #[allow(dead_code)]
+    pub fn rolling_apply<F>(&self, window: usize, f: F) -> Result<Self, MathError>
+    where
+        F: Fn(&[T]) -> T,
+    {
+        if window == 0 || window > self.0.len() {
+            return Err(MathError::InvalidInput(
+                "Window must be between 1 and the vector length",
+            ));
+        }
+        Ok(Vector(self.0.windows(window).map(f).collect()))
+    }
+
+    #[allow(dead_code)]
+    pub fn zip_with<F>(&self, other: &Self, f: F) -> Result<Self, MathError>
+    where
+        T: Copy,
+        F: Fn(T, T) -> T,
+    {
+        if self.0.len() != other.0.len() {
+            return Err(MathError::ShapeMismatch(
+                "Vectors must have the same length",
+            ));
+        }
+        Ok(Vector(
+            self.0
+                .iter()
+                .zip(other.0.iter())
+                .map(|(&x, &y)| f(x, y))
+                .collect(),
+        ))
+    }
+}
+
+impl<T> Vector<T>
+where
+    T: Copy + PartialOrd,
+{
+    #[allow(dead_code)]
+    pub fn cummax(&self) -> Self {
+        self.running(|acc, x| if x > acc { x } else { acc })
+    }
+
+    #[allow(dead_code)]
+    pub fn cummin(&self) -> Self {
+        self.running(|acc, x| if x < acc { x } else { acc })
+    }
+
+    #[allow(dead_code)]
+    pub fn component_max(&self, other: &Self) -> Result<Self, MathError> {
+        self.zip_with(other, |x, y| if y > x { y } else { x })
+    }
+
+    #[allow(dead_code)]
+    pub fn component_min(&self, other: &Self) -> Result<Self, MathError> {
+        self.zip_with(other, |x, y| if y < x { y } else { x })
+    }
+
+    fn running(&self, pick: impl Fn(T, T) -> T) -> Self {
+        let mut out = Vec::with_capacity(self.0.len());
+        for &x in &self.0 {
+            let next = match out.last() {
+                Some(&acc) => pick(acc, x),
+                None => x,
+            };
+            out.push(next);
+        }
+        Vector(out)
+    }
+}
+
+impl Vector<f64> {
+    #[allow(dead_code)]
+    pub fn weighted_sample(&self, seed: u64) -> Result<usize, MathError> {
+        if self.0.is_empty() {
+            return Err(MathError::Empty("Vector must have at least one element"));
+        }
+        if self.0.iter().any(|&w| w < 0.0 || !w.is_finite()) {
+            return Err(MathError::InvalidInput(
+                "Weights must be finite and non-negative",
+            ));
+        }
+        let total: f64 = self.0.iter().sum();
+        if total == 0.0 {
+            return Err(MathError::DivisionByZero);
+        }
+
+        // Weights that do not sum to 1 are normalized implicitly
+        let target = SplitMix64::new(seed).next_f64() * total;
+        let mut acc = 0.0;
+        for (i, &w) in self.0.iter().enumerate() {
+            acc += w;
+            if target < acc {
+                return Ok(i);
+            }
+        }
+
+        // Rounding can leave target just above the final sum
+        Ok(self.0.iter().rposition(|&w| w > 0.0).unwrap())
+    }
+
+    #[allow(dead_code)]
+    pub fn one_hot(index: usize, len: usize) -> Result<Vector<f64>, MathError> {
+        if index >= len {
+            return Err(MathError::IndexOutOfBounds(
+                "Index must be less than length",
+            ));
+        }
+        let mut v = vec![0.0; len];
+        v[index] = 1.0;
+        Ok(Vector(v))
+    }
+
+    /// Snaps a soft vector to the one-hot of its largest component (first on ties)
+    #[allow(dead_code)]
+    pub fn argmax_one_hot(&self) -> Result<Vector<f64>, MathError> {
+        let index = self
+            .0
+            .iter()
+            .enumerate()
+            .fold(None, |best: Option<(usize, f64)>, (i, &x)| match best {
+                Some((_, b)) if b >= x => best,
+                _ => Some((i, x)),
+            })
+            .ok_or(MathError::Empty("Vector must have at least one element"))?
+            .0;
+        Vector::one_hot(index, self.0.len())
+    }
 }
 
 impl<T> fmt::Display for Vector<T>
@@ -137,34 +526,65 @@ where
 }
 
 #[allow(dead_code)]
-pub fn add_vectors(a: &Vec<f64>, b: &Vec<f64>) -> Result<Vec<f64>, &'static str> {
+pub fn add_vectors(a: &[f64], b: &[f64]) -> Result<Vec<f64>, MathError> {
     if a.len() != b.len() {
-        return Err("Vectors must have the same length");
+        return Err(MathError::ShapeMismatch(
+            "Vectors must have the same length",
+        ));
     }
 
     Ok(a.iter().zip(b.iter()).map(|(x, y)| x + y).collect())
 }
 
 #[allow(dead_code)]
-pub fn sub_vectors(a: &Vec<f64>, b: &Vec<f64>) -> Result<Vec<f64>, &'static str> {
+pub fn sub_vectors(a: &[f64], b: &[f64]) -> Result<Vec<f64>, MathError> {
     if a.len() != b.len() {
-        return Err("Vectors must have the same length");
+        return Err(MathError::ShapeMismatch(
+            "Vectors must have the same length",
+        ));
     }
 
     Ok(a.iter().zip(b.iter()).map(|(x, y)| x - y).collect())
 }
 
 #[allow(dead_code)]
-pub fn mult_vectors(a: &Vec<f64>, b: &Vec<f64>) -> Result<Vec<f64>, &'static str> {
+pub fn mult_vectors(a: &[f64], b: &[f64]) -> Result<Vec<f64>, MathError> {
     if a.len() != b.len() {
-        return Err("Vectors must have the same length");
+        return Err(MathError::ShapeMismatch(
+            "Vectors must have the same length",
+        ));
     }
 
     Ok(a.iter().zip(b.iter()).map(|(x, y)| x * y).collect())
 }
 
 #[allow(dead_code)]
-pub fn operate_vectors<T>(a: &[T], b: &[T], op: VectorOp) -> Result<Vector<T>, &'static str>
+pub fn blend(vectors: &[Vector<f64>], weights: &[f64]) -> Result<Vector<f64>, MathError> {
+    if vectors.len() != weights.len() {
+        return Err(MathError::ShapeMismatch(
+            "Number of vectors and weights must match",
+        ));
+    }
+    let first = vectors
+        .first()
+        .ok_or(MathError::Empty("At least one vector is required"))?;
+    if vectors.iter().any(|v| v.len() != first.len()) {
+        return Err(MathError::ShapeMismatch(
+            "Vectors must have the same length",
+        ));
+    }
+
+    let mut acc = vec![0.0; first.len()];
+    for (v, &w) in vectors.iter().zip(weights) {
+        for (a, &x) in acc.iter_mut().zip(&v.0) {
+            *a += w * x;
+        }
+    }
+    Ok(Vector(acc))
+}
+
+#[allow(dead_code)]
+pub fn operate_vectors<T>(a: &[T], b: &[T], op: VectorOp) -> Result<Vector<T>, MathError>
 where
     T: Copy
         + Add<Output = T>
@@ -175,7 +595,9 @@ where
         + PartialEq,
 {
     if a.len() != b.len() {
-        return Err("Vectors must have the same length");
+        return Err(MathError::ShapeMismatch(
+            "Vectors must have the same length",
+        ));
     }
 
     let result = a
@@ -188,7 +610,7 @@ where
                 VectorOp::Mul => x * y,
                 VectorOp::Div => {
                     if y == T::default() {
-                        return Err("Division by zero");
+                        return Err(MathError::DivisionByZero);
                     }
                     x / y
                 }
@@ -202,6 +624,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::format;
     #[test]
     fn it_should_sum_correctly() {
         let a: Vec<f64> = vec![1.0, 2.0, 3.0, 4.0];
@@ -263,7 +686,7 @@ mod tests {
 
         let result = operate_vectors(&a, &b, VectorOp::Div);
         assert!(result.is_err());
-        assert_eq!(result.unwrap_err(), "Division by zero");
+        assert_eq!(result.unwrap_err(), MathError::DivisionByZero);
     }
 
     #[test]
@@ -281,7 +704,10 @@ mod tests {
 
         let result = operate_vectors(&a, &b, VectorOp::Add);
         assert!(result.is_err());
-        assert_eq!(result.unwrap_err(), "Vectors must have the same length");
+        assert_eq!(
+            result.unwrap_err(),
+            MathError::ShapeMismatch("Vectors must have the same length")
+        );
     }
 
     #[test]
@@ -323,6 +749,314 @@ mod tests {
         let v = Vector(vec![0.0, 0.0]);
         let result = v.unit_vect();
         assert!(result.is_err());
-        assert_eq!(result.unwrap_err(), "Cannot normalize zero vector");
+        assert_eq!(result.unwrap_err(), MathError::DivisionByZero);
+    }
+
+    #[test]
+    fn it_should_sample_one_hot_deterministically() {
+        let v = Vector(vec![0.0, 0.0, 1.0, 0.0]);
+        for seed in 0..100 {
+            assert_eq!(v.weighted_sample(seed).unwrap(), 2);
+        }
+    }
+
+    #[test]
+    fn it_should_sample_uniform_weights_roughly_uniformly() {
+        let v = Vector(vec![0.25; 4]);
+        let mut counts = [0usize; 4];
+        for seed in 0..10_000 {
+            counts[v.weighted_sample(seed).unwrap()] += 1;
+        }
+        for &c in &counts {
+            assert!((2_250..=2_750).contains(&c), "counts: {:?}", counts);
+        }
+    }
+
+    #[test]
+    fn it_should_return_error_for_negative_weights() {
+        let v = Vector(vec![0.5, -0.5, 1.0]);
+        let result = v.weighted_sample(0);
+        assert_eq!(
+            result.unwrap_err(),
+            MathError::InvalidInput("Weights must be finite and non-negative")
+        );
+    }
+
+    #[test]
+    fn it_should_return_cummax_correctly() {
+        let v = Vector(vec![1, 3, 2, 5, 4]);
+        assert_eq!(v.cummax(), Vector(vec![1, 3, 3, 5, 5]));
+    }
+
+    #[test]
+    fn it_should_return_cummin_correctly() {
+        let v = Vector(vec![4.0, 5.0, 2.0, 3.0, 1.0]);
+        assert_eq!(v.cummin(), Vector(vec![4.0, 4.0, 2.0, 2.0, 1.0]));
+        assert_eq!(Vector::<f64>(vec![]).cummin(), Vector(vec![]));
+    }
+
+    #[test]
+    fn it_should_interpolate_at_position_correctly() {
+        let v = Vector(vec![0.0, 10.0, 20.0]);
+        assert_eq!(v.interp_at(0.5).unwrap(), 5.0);
+        assert_eq!(v.interp_at(1.25).unwrap(), 12.5);
+        assert_eq!(v.interp_at(2.0).unwrap(), 20.0);
+    }
+
+    #[test]
+    fn it_should_return_error_for_interp_out_of_range() {
+        let v = Vector(vec![0.0, 10.0, 20.0]);
+        assert_eq!(
+            v.interp_at(2.5).unwrap_err(),
+            MathError::IndexOutOfBounds("Position out of range")
+        );
+        assert_eq!(
+            v.interp_at(-0.1).unwrap_err(),
+            MathError::IndexOutOfBounds("Position out of range")
+        );
+        let empty: Vector<f64> = Vector(vec![]);
+        assert!(empty.interp_at(0.0).is_err());
+    }
+
+    #[test]
+    fn it_should_return_dot_prefix_correctly() {
+        let a = Vector(vec![1, 2, 3]);
+        let b = Vector(vec![1, 1, 1]);
+        assert_eq!(a.dot_prefix(&b).unwrap(), Vector(vec![1, 3, 6]));
+
+        let c = Vector(vec![1.0, 2.0, 3.0]);
+        let d = Vector(vec![4.0, 5.0, 6.0]);
+        let prefix = c.dot_prefix(&d).unwrap();
+        assert_eq!(*prefix.0.last().unwrap(), c.dot_product(&d).unwrap());
+    }
+
+    #[test]
+    fn it_should_return_moving_mean_correctly() {
+        let v = Vector(vec![1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(v.moving_mean(2).unwrap(), Vector(vec![1.5, 2.5, 3.5]));
+        assert_eq!(v.moving_mean(4).unwrap(), Vector(vec![2.5]));
+    }
+
+    #[test]
+    fn it_should_return_moving_std_correctly() {
+        let v = Vector(vec![1.0, 3.0, 3.0, 7.0]);
+        assert_eq!(v.moving_std(2).unwrap(), Vector(vec![1.0, 0.0, 2.0]));
+    }
+
+    #[test]
+    fn it_should_return_error_for_invalid_window() {
+        let v = Vector(vec![1.0, 2.0, 3.0]);
+        assert!(v.moving_mean(0).is_err());
+        assert!(v.moving_std(4).is_err());
+    }
+
+    #[test]
+    fn it_should_return_cross_entropy_correctly() {
+        let predicted = Vector(vec![0.25, 0.75]);
+        let target = Vector(vec![0.5, 0.5]);
+        let expected = -(0.5 * 0.25_f64.ln() + 0.5 * 0.75_f64.ln());
+        let result = predicted.cross_entropy(&target).unwrap();
+        assert!((result - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn it_should_return_kl_divergence_correctly() {
+        let p = Vector(vec![0.5, 0.5]);
+        let q = Vector(vec![0.25, 0.75]);
+        let expected = 0.5 * (2.0_f64).ln() + 0.5 * (0.5_f64 / 0.75).ln();
+        assert!((p.kl_divergence(&q).unwrap() - expected).abs() < 1e-12);
+        assert_eq!(p.kl_divergence(&p).unwrap(), 0.0);
+    }
+
+    #[test]
+    fn it_should_return_error_for_kl_divergence_with_missing_support() {
+        let p = Vector(vec![0.5, 0.5]);
+        let q = Vector(vec![1.0, 0.0]);
+        assert_eq!(
+            p.kl_divergence(&q).unwrap_err(),
+            MathError::InvalidInput("Support of other must cover support of self")
+        );
+        assert!(Vector(vec![-0.5, 1.5]).cross_entropy(&p).is_err());
+    }
+
+    #[test]
+    fn it_should_return_dot_iter_correctly() {
+        let a = Vector(vec![1.0, 2.0, 3.0]);
+        let result = a.dot_iter((0..3).map(|i| i as f64 * 2.0)).unwrap();
+        assert_eq!(result, 16.0);
+        assert_eq!(result, a.dot_product(&Vector(vec![0.0, 2.0, 4.0])).unwrap());
+    }
+
+    #[test]
+    fn it_should_return_error_for_dot_iter_length_mismatch() {
+        let a = Vector(vec![1.0, 2.0, 3.0]);
+        assert!(a.dot_iter((0..2).map(|i| i as f64)).is_err());
+        assert!(a.dot_iter((0..4).map(|i| i as f64)).is_err());
+    }
+
+    #[test]
+    fn it_should_return_entropy_correctly() {
+        let uniform = Vector(vec![0.25; 4]);
+        assert!((uniform.entropy().unwrap() - 4.0_f64.ln()).abs() < 1e-12);
+        assert!((uniform.entropy_base(2.0).unwrap() - 2.0).abs() < 1e-12);
+
+        let one_hot = Vector(vec![0.0, 1.0, 0.0]);
+        assert_eq!(one_hot.entropy().unwrap(), 0.0);
+    }
+
+    #[test]
+    fn it_should_return_error_for_entropy_of_negative_probabilities() {
+        let v = Vector(vec![-0.5, 1.5]);
+        assert_eq!(
+            v.entropy().unwrap_err(),
+            MathError::InvalidInput("Probabilities must be non-negative")
+        );
+    }
+
+    #[test]
+    fn it_should_rolling_apply_correctly() {
+        let v = Vector(vec![1.0, 4.0, 2.0, 8.0, 5.0]);
+        let sums = v.rolling_apply(3, |w| w.iter().sum()).unwrap();
+        let means = v.moving_mean(3).unwrap();
+        assert_eq!(sums, Vector(vec![7.0, 14.0, 15.0]));
+        for (s, m) in sums.0.iter().zip(means.0.iter()) {
+            assert!((s - m * 3.0).abs() < 1e-12);
+        }
+
+        let maxes = v.rolling_apply(2, |w| w.iter().cloned().fold(f64::MIN, f64::max));
+        assert_eq!(maxes.unwrap(), Vector(vec![4.0, 4.0, 8.0, 8.0]));
+        assert!(v.rolling_apply(6, |w| w[0]).is_err());
+    }
+
+    #[test]
+    fn it_should_return_vector_projection_correctly() {
+        let a = Vector(vec![3.0, 4.0]);
+        let b = Vector(vec![2.0, 0.0]);
+        assert_eq!(a.vector_projection(&b).unwrap(), Vector(vec![3.0, 0.0]));
+        assert!(a.vector_projection(&Vector(vec![0.0, 0.0])).is_err());
+    }
+
+    #[test]
+    fn it_should_zip_with_closure_correctly() {
+        let a = Vector(vec![1.0, 5.0, 3.0]);
+        let b = Vector(vec![4.0, 2.0, 3.0]);
+        let max = a.zip_with(&b, f64::max).unwrap();
+        assert_eq!(max, a.component_max(&b).unwrap());
+        assert_eq!(max, Vector(vec![4.0, 5.0, 3.0]));
+        assert_eq!(a.component_min(&b).unwrap(), Vector(vec![1.0, 2.0, 3.0]));
+        assert_eq!(a.zip_with(&b, |x, y| x + y).unwrap(), a.add(&b).unwrap());
+    }
+
+    #[test]
+    fn it_should_return_error_for_zip_with_length_mismatch() {
+        let a = Vector(vec![1, 2]);
+        let b = Vector(vec![1, 2, 3]);
+        let result = a.zip_with(&b, |x, y| x * y);
+        assert_eq!(
+            result.unwrap_err(),
+            MathError::ShapeMismatch("Vectors must have the same length")
+        );
+    }
+
+    #[test]
+    fn it_should_build_one_hot_correctly() {
+        assert_eq!(
+            Vector::one_hot(2, 4).unwrap(),
+            Vector(vec![0.0, 0.0, 1.0, 0.0])
+        );
+        assert_eq!(
+            Vector::one_hot(4, 4).unwrap_err(),
+            MathError::IndexOutOfBounds("Index must be less than length")
+        );
+    }
+
+    #[test]
+    fn it_should_snap_softmax_to_one_hot() {
+        let soft = Vector(vec![0.1, 0.7, 0.2]);
+        assert_eq!(soft.argmax_one_hot().unwrap(), Vector(vec![0.0, 1.0, 0.0]));
+        let tie = Vector(vec![0.4, 0.4, 0.2]);
+        assert_eq!(tie.argmax_one_hot().unwrap(), Vector(vec![1.0, 0.0, 0.0]));
+        assert!(Vector(vec![]).argmax_one_hot().is_err());
+    }
+
+    #[test]
+    fn it_should_standardize_and_reapply_fitted_parameters() {
+        let train = Vector(vec![2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]);
+        let (z, mean, std) = train.standardize().unwrap();
+        assert_eq!((mean, std), (5.0, 2.0));
+        assert_eq!(z.0[0], -1.5);
+
+        let fresh = Vector(vec![5.0, 11.0, 1.0]);
+        let scaled = fresh.apply_standardization(mean, std).unwrap();
+        assert_eq!(scaled, Vector(vec![0.0, 3.0, -2.0]));
+    }
+
+    #[test]
+    fn it_should_return_error_for_standardize_of_constant_vector() {
+        let v = Vector(vec![3.0, 3.0, 3.0]);
+        assert_eq!(v.standardize().unwrap_err(), MathError::DivisionByZero);
+    }
+
+    #[test]
+    fn it_should_blend_weighted_vectors() {
+        let vs = [
+            Vector(vec![1.0, 0.0, 2.0]),
+            Vector(vec![0.0, 4.0, 2.0]),
+            Vector(vec![2.0, 2.0, -2.0]),
+        ];
+        let result = blend(&vs, &[0.5, 0.25, 1.0]).unwrap();
+        assert_eq!(result, Vector(vec![2.5, 3.0, -0.5]));
+    }
+
+    #[test]
+    fn it_should_return_error_for_blend_mismatch() {
+        let vs = [Vector(vec![1.0, 2.0]), Vector(vec![1.0])];
+        assert_eq!(
+            blend(&vs, &[1.0]).unwrap_err(),
+            MathError::ShapeMismatch("Number of vectors and weights must match")
+        );
+        assert_eq!(
+            blend(&vs, &[1.0, 1.0]).unwrap_err(),
+            MathError::ShapeMismatch("Vectors must have the same length")
+        );
+        assert!(blend(&[], &[]).is_err());
+    }
+
+    #[test]
+    fn it_should_compute_cross_product() {
+        let a = Vector(vec![1.0, 0.0, 0.0]);
+        let b = Vector(vec![0.0, 1.0, 0.0]);
+        assert_eq!(a.cross(&b).unwrap(), Vector(vec![0.0, 0.0, 1.0]));
+        assert_eq!(b.cross(&a).unwrap(), Vector(vec![0.0, 0.0, -1.0]));
+        assert!(
+            Vector(vec![1.0, 2.0])
+                .cross(&Vector(vec![3.0, 4.0]))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn it_should_compute_weighted_dot_product() {
+        let a = Vector(vec![1.0, 2.0, 3.0]);
+        let b = Vector(vec![4.0, -5.0, 6.0]);
+        let ones = Vector(vec![1.0, 1.0, 1.0]);
+        assert_eq!(
+            a.weighted_dot(&b, &ones).unwrap(),
+            a.dot_product(&b).unwrap()
+        );
+        assert_eq!(
+            a.weighted_dot(&b, &Vector(vec![0.5, 2.0, 0.0])).unwrap(),
+            2.0 - 20.0
+        );
+        assert!(a.weighted_dot(&b, &Vector(vec![1.0, 1.0])).is_err());
+    }
+
+    #[test]
+    fn it_should_compare_vectors_within_tolerance() {
+        let a = Vector(vec![1.0, 2.0, 3.0]);
+        let b = Vector(vec![1.0, 2.0 + 1e-10, 3.0]);
+        assert!(a.approx_eq(&b, 1e-6));
+        assert!(!a.approx_eq(&b, 1e-12));
+        assert!(!a.approx_eq(&Vector(vec![1.0, 2.0]), 1.0));
     }
 }